@@ -66,16 +66,25 @@ function update_timezone() {
   }
 }
 
+function update_attendance() {
+  let online_only = document.getElementById("attendance").value == "online";
+  document.getElementById("country").required = !online_only;
+  document.getElementById("city").required = !online_only;
+}
+
 function initialise() {
   document.getElementById("links_list").oninput = update_inputs;
   document.getElementById("bands_list").oninput = update_inputs;
   document.getElementById("callers_list").oninput = update_inputs;
+  document.getElementById("teachers_list").oninput = update_inputs;
   document.getElementById("with_time").onchange = update_datetimes;
   document.getElementById("country").onchange = update_timezone;
   document.getElementById("state").onchange = update_timezone;
+  document.getElementById("attendance").onchange = update_attendance;
 
   update_datetimes();
   update_timezone();
+  update_attendance();
 }
 
 window.onload = initialise;
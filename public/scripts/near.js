@@ -0,0 +1,14 @@
+/** Reloads the current page with a filter for events near the user's current location. */
+function near_me(event) {
+  event.preventDefault();
+  navigator.geolocation.getCurrentPosition((position) => {
+    let params = new URLSearchParams(window.location.search);
+    params.set(
+      "near",
+      position.coords.latitude.toFixed(4) +
+        "," +
+        position.coords.longitude.toFixed(4)
+    );
+    window.location.search = params.toString();
+  });
+}
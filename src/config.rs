@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::model::recurrence::RecurrenceWindow;
 use eyre::{Report, WrapErr, bail};
 use serde::Deserialize;
 use std::{
@@ -33,10 +34,15 @@ pub struct Config {
     pub events: String,
     #[serde(default = "default_bind_address")]
     pub bind_address: SocketAddr,
+    /// The URL at which the website is publicly served, used for permalinks.
+    #[serde(default = "default_public_url")]
+    pub public_url: String,
     #[serde(default)]
     pub reload_token: String,
     #[serde(default)]
     pub github: Option<GitHubConfig>,
+    #[serde(default)]
+    pub recurrence_window: RecurrenceWindow,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -82,6 +88,10 @@ fn default_bind_address() -> SocketAddr {
     "0.0.0.0:3002".parse().unwrap()
 }
 
+fn default_public_url() -> String {
+    "https://folkdance.page".to_string()
+}
+
 fn default_main_branch() -> String {
     "main".to_string()
 }
@@ -17,7 +17,10 @@ pub mod bands;
 pub mod callers;
 pub mod cities;
 pub mod edit;
+pub mod event;
 mod event_form;
 pub mod index;
 pub mod organisations;
+mod performer;
 pub mod reload;
+pub mod teachers;
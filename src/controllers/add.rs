@@ -19,8 +19,9 @@ use crate::{
     github::{add_event_to_file, choose_file_for_event},
     model::{
         event::Event,
-        events::{Band, Caller, Country, Events, Organisation},
+        events::{Band, Caller, Country, Events, Organisation, Teacher},
         filters::Filters,
+        registries::Registries,
     },
 };
 use askama::Template;
@@ -36,6 +37,7 @@ use url::Url;
 
 pub async fn add(
     events: Events,
+    registries: Registries,
     Query(query): Query<AddQuery>,
 ) -> Result<Html<String>, InternalError> {
     let form = if let Some(hash) = &query.hash {
@@ -49,7 +51,7 @@ pub async fn add(
             ..Default::default()
         }
     };
-    let template = AddTemplate::new(&events, form, vec![]);
+    let template = AddTemplate::new(&events, &registries, form, vec![]);
     Ok(Html(template.render()?))
 }
 
@@ -61,39 +63,43 @@ pub struct AddQuery {
 pub async fn submit(
     State(config): State<Arc<Config>>,
     events: Events,
+    registries: Registries,
     Form(form): Form<EventForm>,
 ) -> Result<Html<String>, InternalError> {
     match Event::try_from(form.clone()) {
-        Ok(event) => match choose_file_for_event(&events, &event) {
-            Ok(chosen_file) => {
-                let pr = if let Some(github) = &config.github {
-                    Some(
-                        add_event_to_file(
-                            event.clone(),
-                            &chosen_file,
-                            form.email.as_deref(),
-                            github,
+        Ok(mut event) => {
+            event.id = Some(Event::new_id());
+            match choose_file_for_event(&events, &event) {
+                Ok(chosen_file) => {
+                    let pr = if let Some(github) = &config.github {
+                        Some(
+                            add_event_to_file(
+                                event.clone(),
+                                &chosen_file,
+                                form.email.as_deref(),
+                                github,
+                            )
+                            .await?,
                         )
-                        .await?,
-                    )
-                } else {
-                    None
-                };
+                    } else {
+                        None
+                    };
 
-                let template = SubmitTemplate { pr, event };
-                Ok(Html(template.render()?))
+                    let template = SubmitTemplate { pr, event };
+                    Ok(Html(template.render()?))
+                }
+                Err(duplicate) => {
+                    let template = SubmitFailedTemplate {
+                        event: &event,
+                        existing_event: &duplicate.existing,
+                        merged: &duplicate.merged,
+                    };
+                    Ok(Html(template.render()?))
+                }
             }
-            Err(duplicate) => {
-                let template = SubmitFailedTemplate {
-                    event: &event,
-                    existing_event: &duplicate.existing,
-                    merged: &duplicate.merged,
-                };
-                Ok(Html(template.render()?))
-            }
-        },
+        }
         Err(errors) => {
-            let template = AddTemplate::new(&events, form, errors);
+            let template = AddTemplate::new(&events, &registries, form, errors);
             Ok(Html(template.render()?))
         }
     }
@@ -105,21 +111,29 @@ struct AddTemplate {
     countries: Vec<Country>,
     bands: Vec<Band>,
     callers: Vec<Caller>,
+    teachers: Vec<Teacher>,
     organisations: Vec<Organisation>,
     form: EventForm,
     errors: Vec<&'static str>,
 }
 
 impl AddTemplate {
-    fn new(events: &Events, form: EventForm, errors: Vec<&'static str>) -> Self {
+    fn new(
+        events: &Events,
+        registries: &Registries,
+        form: EventForm,
+        errors: Vec<&'static str>,
+    ) -> Self {
         let countries = events.countries(&Filters::all());
-        let bands = events.bands();
-        let callers = events.callers();
+        let bands = events.bands(&registries.bands);
+        let callers = events.callers(&registries.callers);
+        let teachers = events.teachers();
         let organisations = events.organisations();
         Self {
             countries,
             bands,
             callers,
+            teachers,
             organisations,
             form,
             errors,
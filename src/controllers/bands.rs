@@ -12,21 +12,58 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::performer::{self, PerformerKind};
 use crate::{
+    config::Config,
     errors::InternalError,
-    model::events::{Band, Events},
+    icalendar::Ics,
+    model::{
+        events::{Band, Events},
+        registries::Registries,
+    },
 };
 use askama::Template;
-use axum::response::Html;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+};
+use std::sync::Arc;
 
-pub async fn bands(events: Events) -> Result<Html<String>, InternalError> {
-    let bands = events.bands();
+pub async fn bands(events: Events, registries: Registries) -> Result<Html<String>, InternalError> {
+    let bands = events.bands(&registries.bands);
     let template = BandsTemplate { bands };
     Ok(Html(template.render()?))
 }
 
+pub async fn band(
+    events: Events,
+    registries: Registries,
+    Path(slug): Path<String>,
+) -> Result<Html<String>, InternalError> {
+    performer::page(PerformerKind::Band, events, &registries, slug)
+}
+
+pub async fn band_ics(
+    State(config): State<Arc<Config>>,
+    events: Events,
+    registries: Registries,
+    Path(slug): Path<String>,
+) -> Result<Ics, InternalError> {
+    performer::ics(
+        PerformerKind::Band,
+        events,
+        &registries,
+        &slug,
+        &config.public_url,
+    )
+}
+
 #[derive(Template)]
 #[template(path = "bands.html")]
 struct BandsTemplate {
     bands: Vec<Band>,
 }
+
+mod filters {
+    pub use crate::util::slug;
+}
@@ -12,21 +12,61 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::performer::{self, PerformerKind};
 use crate::{
+    config::Config,
     errors::InternalError,
-    model::events::{Caller, Events},
+    icalendar::Ics,
+    model::{
+        events::{Caller, Events},
+        registries::Registries,
+    },
 };
 use askama::Template;
-use axum::response::Html;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+};
+use std::sync::Arc;
 
-pub async fn callers(events: Events) -> Result<Html<String>, InternalError> {
-    let callers = events.callers();
+pub async fn callers(
+    events: Events,
+    registries: Registries,
+) -> Result<Html<String>, InternalError> {
+    let callers = events.callers(&registries.callers);
     let template = CallersTemplate { callers };
     Ok(Html(template.render()?))
 }
 
+pub async fn caller(
+    events: Events,
+    registries: Registries,
+    Path(slug): Path<String>,
+) -> Result<Html<String>, InternalError> {
+    performer::page(PerformerKind::Caller, events, &registries, slug)
+}
+
+pub async fn caller_ics(
+    State(config): State<Arc<Config>>,
+    events: Events,
+    registries: Registries,
+    Path(slug): Path<String>,
+) -> Result<Ics, InternalError> {
+    performer::ics(
+        PerformerKind::Caller,
+        events,
+        &registries,
+        &slug,
+        &config.public_url,
+    )
+}
+
 #[derive(Template)]
 #[template(path = "callers.html")]
 struct CallersTemplate {
     callers: Vec<Caller>,
 }
+
+mod filters {
+    pub use crate::util::slug;
+}
@@ -19,8 +19,9 @@ use crate::{
     github::edit_event_in_file,
     model::{
         event::Event,
-        events::{Band, Caller, Country, Events, Organisation},
+        events::{Band, Caller, Country, Events, Organisation, Teacher},
         filters::Filters,
+        registries::Registries,
     },
 };
 use askama::Template;
@@ -36,30 +37,44 @@ use url::Url;
 
 pub async fn edit(
     events: Events,
+    registries: Registries,
     Query(query): Query<EditQuery>,
 ) -> Result<Html<String>, InternalError> {
     let event = events
         .with_hash(&query.hash)
         .ok_or_else(|| InternalError::Internal(eyre!("Event not found")))?;
-    let template = EditTemplate::new(&events, EventForm::from_event(event), vec![]);
+    if event.series.is_some() {
+        return Err(InternalError::Internal(eyre!(
+            "Recurring events can't be edited with the form"
+        )));
+    }
+    let template = EditTemplate::new(&events, &registries, EventForm::from_event(event), vec![]);
     Ok(Html(template.render()?))
 }
 
 pub async fn submit(
     State(config): State<Arc<Config>>,
     events: Events,
+    registries: Registries,
     Query(query): Query<EditQuery>,
     Form(form): Form<EventForm>,
 ) -> Result<Html<String>, InternalError> {
     let original_event = events
         .with_hash(&query.hash)
         .ok_or_else(|| InternalError::Internal(eyre!("Event not found")))?;
+    if original_event.series.is_some() {
+        return Err(InternalError::Internal(eyre!(
+            "Recurring events can't be edited with the form"
+        )));
+    }
     let mut original_event_without_source = original_event.clone();
     original_event_without_source.source = None;
     match Event::try_from(form.clone()) {
-        Ok(event) => {
+        Ok(mut event) => {
+            keep_original_details(&mut event, original_event);
             if event == original_event_without_source {
-                let template = EditTemplate::new(&events, form, vec!["Event not changed"]);
+                let template =
+                    EditTemplate::new(&events, &registries, form, vec!["Event not changed"]);
                 Ok(Html(template.render()?))
             } else {
                 let file = original_event
@@ -72,6 +87,7 @@ pub async fn submit(
                             file,
                             &original_event_without_source,
                             event.clone(),
+                            &registries,
                             form.email.as_deref(),
                             github,
                         )
@@ -85,12 +101,33 @@ pub async fn submit(
             }
         }
         Err(errors) => {
-            let template = EditTemplate::new(&events, form, errors);
+            let template = EditTemplate::new(&events, &registries, form, errors);
             Ok(Html(template.render()?))
         }
     }
 }
 
+/// Copies details from the original event which the form doesn't include or can't represent
+/// exactly to the edited event.
+fn keep_original_details(event: &mut Event, original_event: &Event) {
+    // The form doesn't include the ID, venue ID or sessions, so keep the original ones.
+    event.id = original_event.id.clone();
+    event.venue_id = original_event.venue_id.clone();
+    event.sessions = original_event.sessions.clone();
+    // The form only has the price as text, so keep the structured original if it's unchanged.
+    if event.price.as_ref().map(ToString::to_string)
+        == original_event.price.as_ref().map(ToString::to_string)
+    {
+        event.price = original_event.price.clone();
+    }
+    // The form always has a timezone for events with a time, guessed if the original didn't have
+    // one, and never has one for date-only events. Keep the original unless it was changed.
+    if event.timezone.is_none() || event.timezone == EventForm::from_event(original_event).timezone
+    {
+        event.timezone = original_event.timezone;
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct EditQuery {
     hash: String,
@@ -102,21 +139,29 @@ struct EditTemplate {
     countries: Vec<Country>,
     bands: Vec<Band>,
     callers: Vec<Caller>,
+    teachers: Vec<Teacher>,
     organisations: Vec<Organisation>,
     form: EventForm,
     errors: Vec<&'static str>,
 }
 
 impl EditTemplate {
-    fn new(events: &Events, form: EventForm, errors: Vec<&'static str>) -> Self {
+    fn new(
+        events: &Events,
+        registries: &Registries,
+        form: EventForm,
+        errors: Vec<&'static str>,
+    ) -> Self {
         let countries = events.countries(&Filters::all());
-        let bands = events.bands();
-        let callers = events.callers();
+        let bands = events.bands(&registries.bands);
+        let callers = events.callers(&registries.callers);
+        let teachers = events.teachers();
         let organisations = events.organisations();
         Self {
             countries,
             bands,
             callers,
+            teachers,
             organisations,
             form,
             errors,
@@ -134,3 +179,72 @@ struct SubmitTemplate {
 mod filters {
     pub use crate::util::checked_if_true;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::Tz;
+
+    /// Returns the event as it would be submitted from the edit form without any changes.
+    fn round_trip(original: &Event) -> Event {
+        let mut event = Event::try_from(EventForm::from_event(original)).unwrap();
+        keep_original_details(&mut event, original);
+        event
+    }
+
+    #[test]
+    fn unchanged_timed_event_without_timezone() {
+        let original: Event = serde_yaml::from_str(
+            r#"
+name: Contra dance
+start: 2026-03-07T19:00:00+13:00
+end: 2026-03-07T22:00:00+13:00
+country: New Zealand
+city: Dunedin
+styles: [contra]
+social: true
+"#,
+        )
+        .unwrap();
+        assert_eq!(round_trip(&original), original);
+    }
+
+    #[test]
+    fn unchanged_date_only_event_with_timezone() {
+        let original: Event = serde_yaml::from_str(
+            r#"
+name: Folk festival
+start_date: 2026-03-07
+end_date: 2026-03-08
+timezone: Pacific/Auckland
+country: New Zealand
+city: Dunedin
+styles: [contra]
+social: true
+"#,
+        )
+        .unwrap();
+        assert_eq!(round_trip(&original), original);
+    }
+
+    #[test]
+    fn changed_timezone() {
+        let original: Event = serde_yaml::from_str(
+            r#"
+name: Contra dance
+start: 2026-03-07T19:00:00+13:00
+end: 2026-03-07T22:00:00+13:00
+country: New Zealand
+city: Dunedin
+styles: [contra]
+social: true
+"#,
+        )
+        .unwrap();
+        let mut form = EventForm::from_event(&original);
+        form.timezone = Some(Tz::Pacific__Chatham);
+        let mut event = Event::try_from(form).unwrap();
+        keep_original_details(&mut event, &original);
+        assert_eq!(event.timezone, Some(Tz::Pacific__Chatham));
+    }
+}
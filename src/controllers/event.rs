@@ -0,0 +1,178 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    config::Config,
+    errors::InternalError,
+    icalendar::{Ics, description, location},
+    model::{
+        event::{Event, EventTime},
+        events::Events,
+        session::{LineupDay, lineup},
+    },
+};
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+};
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use url::Url;
+
+pub async fn event(
+    State(config): State<Arc<Config>>,
+    events: Events,
+    Path(id): Path<String>,
+) -> Result<Html<String>, InternalError> {
+    let event = events.with_hash(&id).ok_or(InternalError::NotFound)?;
+    let template = EventTemplate {
+        public_url: config.public_url.trim_end_matches('/').to_owned(),
+        id,
+        event: event.to_owned(),
+    };
+    Ok(Html(template.render()?))
+}
+
+pub async fn event_ics(
+    State(config): State<Arc<Config>>,
+    events: Events,
+    Path(id): Path<String>,
+) -> Result<Ics, InternalError> {
+    let event = events.with_hash(&id).ok_or(InternalError::NotFound)?;
+    // Give only this instance of a recurring event, rather than the whole series.
+    let event = Event {
+        series: None,
+        ..event.clone()
+    };
+    let events = std::slice::from_ref(&event);
+    Ok(Ics::from_events(
+        events,
+        events,
+        &event.name,
+        &config.public_url,
+    ))
+}
+
+pub async fn event_lineup(
+    events: Events,
+    Path(id): Path<String>,
+) -> Result<Html<String>, InternalError> {
+    let lineup = Lineup::for_event(&events, &id)?;
+    let template = LineupTemplate { id, lineup };
+    Ok(Html(template.render()?))
+}
+
+pub async fn event_lineup_json(
+    events: Events,
+    Path(id): Path<String>,
+) -> Result<String, InternalError> {
+    let lineup = Lineup::for_event(&events, &id)?;
+    Ok(serde_json::to_string(&lineup)?)
+}
+
+/// The line-up of a festival or other multi-day event, by day.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+struct Lineup {
+    name: String,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    /// All bands playing at the event, including those not yet assigned to a session.
+    bands: Vec<String>,
+    /// All callers calling at the event, including those not yet assigned to a session.
+    callers: Vec<String>,
+    /// All teachers teaching at the event, including those not yet assigned to a session.
+    teachers: Vec<String>,
+    days: Vec<LineupDay>,
+}
+
+impl Lineup {
+    /// Returns the line-up of the multi-day event with the given hash or ID.
+    fn for_event(events: &Events, id: &str) -> Result<Self, InternalError> {
+        let event = events
+            .with_hash(id)
+            .filter(|event| event.multiday())
+            .ok_or(InternalError::NotFound)?;
+        Ok(Self {
+            name: event.name.clone(),
+            start_date: event.time.start_date(),
+            end_date: event.time.end_date(),
+            bands: event.bands.clone(),
+            callers: event.callers.clone(),
+            teachers: event.teachers.clone(),
+            days: lineup(&event.sessions),
+        })
+    }
+}
+
+#[derive(Template)]
+#[template(path = "lineup.html")]
+struct LineupTemplate {
+    /// The ID used in the event's permalink.
+    id: String,
+    lineup: Lineup,
+}
+
+#[derive(Template)]
+#[template(path = "event.html")]
+struct EventTemplate {
+    /// The ID used in the event's permalink.
+    id: String,
+    /// The URL at which the website is publicly served, without a trailing slash.
+    public_url: String,
+    event: Event,
+}
+
+impl EventTemplate {
+    /// Returns a short summary of when and where the event is, for link previews.
+    fn summary(&self) -> String {
+        format!("{}, {}", self.event.long_time(), location(&self.event))
+    }
+
+    /// Returns a link to add the event to Google Calendar.
+    fn google_calendar_url(&self) -> Url {
+        let dates = match self.event.time {
+            EventTime::DateOnly {
+                start_date,
+                end_date,
+            } => format!(
+                "{}/{}",
+                start_date.format("%Y%m%d"),
+                // The end date is non-inclusive, so add one day.
+                end_date.succ_opt().unwrap().format("%Y%m%d")
+            ),
+            EventTime::DateTime { start, end } => format!(
+                "{}/{}",
+                start.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ"),
+                end.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ")
+            ),
+        };
+        Url::parse_with_params(
+            "https://calendar.google.com/calendar/render",
+            &[
+                ("action", "TEMPLATE"),
+                ("text", &self.event.name),
+                ("dates", &dates),
+                ("details", &description(&self.event, &self.public_url)),
+                ("location", &location(&self.event)),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+mod filters {
+    pub use crate::util::slug;
+}
@@ -14,16 +14,23 @@
 
 use crate::{
     model::{
+        attendance::AttendanceMode,
         dancestyle::DanceStyle,
         event::{Event, EventTime},
+        inclusivity::{Accessibility, Level, RoleTerms},
+        price::Price,
+        status::Status,
     },
-    util::{default_timezone_for, local_datetime_to_fixed_offset},
+    util::{date_or_none, default_timezone_for, local_datetime_to_fixed_offset},
 };
 use chrono::{NaiveDate, NaiveDateTime};
 use chrono_tz::{TZ_VARIANTS, Tz};
-use serde::{Deserialize, Deserializer, de::IntoDeserializer};
+use serde::{
+    Deserialize, Deserializer,
+    de::{Error, IntoDeserializer},
+};
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
 pub struct EventForm {
     #[serde(deserialize_with = "trim")]
     pub name: String,
@@ -48,6 +55,18 @@ pub struct EventForm {
     pub state: Option<String>,
     #[serde(deserialize_with = "trim")]
     pub city: String,
+    #[serde(deserialize_with = "trim_non_empty")]
+    pub venue: Option<String>,
+    #[serde(deserialize_with = "trim_non_empty")]
+    pub address: Option<String>,
+    #[serde(deserialize_with = "float_or_none")]
+    pub latitude: Option<f64>,
+    #[serde(deserialize_with = "float_or_none")]
+    pub longitude: Option<f64>,
+    #[serde(default)]
+    pub attendance: AttendanceMode,
+    #[serde(deserialize_with = "trim_non_empty")]
+    pub stream_url: Option<String>,
     #[serde(default)]
     pub styles: Vec<DanceStyle>,
     #[serde(default)]
@@ -58,12 +77,26 @@ pub struct EventForm {
     pub bands: Vec<String>,
     #[serde(deserialize_with = "trim_non_empty_vec")]
     pub callers: Vec<String>,
+    #[serde(deserialize_with = "trim_non_empty_vec")]
+    pub teachers: Vec<String>,
+    #[serde(default, deserialize_with = "enum_or_none")]
+    pub level: Option<Level>,
+    #[serde(default, deserialize_with = "enum_or_none")]
+    pub role_terms: Option<RoleTerms>,
+    #[serde(default)]
+    pub no_partner_needed: bool,
+    #[serde(default)]
+    pub family_friendly: bool,
+    #[serde(default)]
+    pub accessibility: Vec<Accessibility>,
     #[serde(deserialize_with = "trim_non_empty")]
     pub price: Option<String>,
     #[serde(deserialize_with = "trim_non_empty")]
     pub organisation: Option<String>,
     #[serde(default)]
-    pub cancelled: bool,
+    pub status: Status,
+    #[serde(default, deserialize_with = "trim_non_empty")]
+    pub rescheduled_to: Option<String>,
     #[serde(deserialize_with = "trim_non_empty")]
     pub email: Option<String>,
 }
@@ -101,6 +134,22 @@ impl EventForm {
         }
     }
 
+    pub fn latitude_string(&self) -> String {
+        if let Some(latitude) = self.latitude {
+            latitude.to_string()
+        } else {
+            String::default()
+        }
+    }
+
+    pub fn longitude_string(&self) -> String {
+        if let Some(longitude) = self.longitude {
+            longitude.to_string()
+        } else {
+            String::default()
+        }
+    }
+
     pub fn from_event(event: &Event) -> Self {
         let (with_time, start_date, end_date, start, end, timezone) = match event.time {
             EventTime::DateOnly {
@@ -108,7 +157,9 @@ impl EventForm {
                 end_date,
             } => (false, Some(start_date), Some(end_date), None, None, None),
             EventTime::DateTime { start, end } => {
-                let timezone = default_timezone_for(&event.country, event.state.as_deref())
+                let timezone = event
+                    .timezone
+                    .or_else(|| default_timezone_for(&event.country, event.state.as_deref()))
                     .filter(|timezone| {
                         // Check that timezone is plausible.
                         local_datetime_to_fixed_offset(&start.naive_local(), *timezone)
@@ -145,14 +196,27 @@ impl EventForm {
             country: event.country.clone(),
             state: event.state.clone(),
             city: event.city.clone(),
+            venue: event.venue.clone(),
+            address: event.address.clone(),
+            latitude: event.latitude,
+            longitude: event.longitude,
+            attendance: event.attendance,
+            stream_url: event.stream_url.clone(),
             styles: event.styles.clone(),
             workshop: event.workshop,
             social: event.social,
             bands: event.bands.clone(),
             callers: event.callers.clone(),
-            price: event.price.clone(),
+            teachers: event.teachers.clone(),
+            level: event.level,
+            role_terms: event.role_terms,
+            no_partner_needed: event.no_partner_needed,
+            family_friendly: event.family_friendly,
+            accessibility: event.accessibility.clone(),
+            price: event.price.as_ref().map(ToString::to_string),
             organisation: event.organisation.clone(),
-            cancelled: event.cancelled,
+            status: event.status,
+            rescheduled_to: event.rescheduled_to.clone(),
             email: None,
         }
     }
@@ -162,9 +226,9 @@ impl TryFrom<EventForm> for Event {
     type Error = Vec<&'static str>;
 
     fn try_from(form: EventForm) -> Result<Self, Self::Error> {
-        let time = if form.with_time {
+        let (time, timezone) = if form.with_time {
             let timezone = form.timezone.ok_or_else(|| vec!["Missing timezone"])?;
-            EventTime::DateTime {
+            let time = EventTime::DateTime {
                 start: local_datetime_to_fixed_offset(
                     &form.start.ok_or_else(|| vec!["Missing start time"])?,
                     timezone,
@@ -175,21 +239,32 @@ impl TryFrom<EventForm> for Event {
                     timezone,
                 )
                 .ok_or_else(|| vec!["Invalid time for timezone"])?,
-            }
+            };
+            (time, Some(timezone))
         } else {
-            EventTime::DateOnly {
+            let time = EventTime::DateOnly {
                 start_date: form.start_date.ok_or_else(|| vec!["Missing start date"])?,
                 end_date: form.end_date.ok_or_else(|| vec!["Missing end date"])?,
-            }
+            };
+            (time, None)
         };
         let event = Self {
+            id: None,
             name: form.name,
             details: form.details,
             links: form.links,
             time,
+            timezone,
             country: form.country,
             state: form.state,
             city: form.city,
+            venue_id: None,
+            venue: form.venue,
+            address: form.address,
+            latitude: form.latitude,
+            longitude: form.longitude,
+            attendance: form.attendance,
+            stream_url: form.stream_url,
             styles: form.styles,
             workshop: form.workshop,
             social: form.social,
@@ -203,9 +278,23 @@ impl TryFrom<EventForm> for Event {
                 .into_iter()
                 .filter_map(trimmed_non_empty)
                 .collect(),
-            price: form.price,
+            teachers: form
+                .teachers
+                .into_iter()
+                .filter_map(trimmed_non_empty)
+                .collect(),
+            sessions: vec![],
+            level: form.level,
+            role_terms: form.role_terms,
+            no_partner_needed: form.no_partner_needed,
+            family_friendly: form.family_friendly,
+            accessibility: form.accessibility,
+            price: form.price.map(Price::from),
             organisation: form.organisation,
-            cancelled: form.cancelled,
+            status: form.status,
+            rescheduled_to: form.rescheduled_to,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let problems = event.validate();
@@ -240,28 +329,39 @@ fn trim_non_empty_vec<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<
     Ok(s.into_iter().filter_map(trimmed_non_empty).collect())
 }
 
-fn date_or_none<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<NaiveDate>, D::Error> {
+fn datetime_or_none<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NaiveDateTime>, D::Error> {
     if let Some(str) = Option::<String>::deserialize(deserializer)? {
         if str.is_empty() {
             Ok(None)
         } else {
-            Ok(Some(NaiveDate::deserialize(str.into_deserializer())?))
+            Ok(Some(NaiveDateTime::deserialize(
+                format!("{str}:00").into_deserializer(),
+            )?))
         }
     } else {
         Ok(None)
     }
 }
 
-fn datetime_or_none<'de, D: Deserializer<'de>>(
+/// Deserializes an enum value from its tag, or `None` for an empty string.
+fn enum_or_none<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
     deserializer: D,
-) -> Result<Option<NaiveDateTime>, D::Error> {
+) -> Result<Option<T>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(str) if !str.is_empty() => Ok(Some(T::deserialize(str.into_deserializer())?)),
+        _ => Ok(None),
+    }
+}
+
+fn float_or_none<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
     if let Some(str) = Option::<String>::deserialize(deserializer)? {
+        let str = str.trim();
         if str.is_empty() {
             Ok(None)
         } else {
-            Ok(Some(NaiveDateTime::deserialize(
-                format!("{str}:00").into_deserializer(),
-            )?))
+            Ok(Some(str.parse().map_err(D::Error::custom)?))
         }
     } else {
         Ok(None)
@@ -13,17 +13,23 @@
 // limitations under the License.
 
 use crate::{
+    config::Config,
     errors::InternalError,
-    icalendar::{Ics, events_to_calendar},
+    icalendar::Ics,
     model::{
         dancestyle::DanceStyle,
         event::Event,
         events::{Country, Events},
         filters::Filters,
+        series::Series,
+        status::Status,
     },
 };
 use askama::Template;
-use axum::{extract::Query, response::Html};
+use axum::{
+    extract::{Query, State},
+    response::Html,
+};
 use axum_extra::{TypedHeader, headers::Host};
 use base64::{Engine, engine::general_purpose::STANDARD};
 use chrono::{Datelike, Months, NaiveDate};
@@ -31,6 +37,7 @@ use fast_qr::{
     QRBuilder,
     convert::{Builder, image::ImageBuilder},
 };
+use std::sync::Arc;
 
 pub async fn index(
     events: Events,
@@ -83,7 +90,18 @@ pub async fn index_html(
         vec![]
     };
     let events = events.sorted_matching(&filters);
-    let months = group_by_month(events.events);
+    // The calendar needs every date, so only collapse series for the list.
+    let months = if calendar || !filters.collapse_series() {
+        group_by_month(
+            events
+                .events
+                .into_iter()
+                .map(|event| (event, None))
+                .collect(),
+        )
+    } else {
+        group_by_month(events.collapse_series())
+    };
     let template = IndexTemplate {
         filters,
         months,
@@ -123,17 +141,23 @@ pub async fn index_yaml(
 }
 
 pub async fn index_ics(
+    State(config): State<Arc<Config>>,
     events: Events,
     Query(mut filters): Query<Filters>,
 ) -> Result<Ics, InternalError> {
     // Default to hiding cancelled events unless the filter explicitly asks for them.
-    if filters.cancelled.is_none() {
-        filters.cancelled = Some(false);
+    if filters.status.is_empty() && filters.exclude_status.is_empty() && filters.cancelled.is_none()
+    {
+        filters.exclude_status.insert(Status::Cancelled);
     }
 
-    let events = events.sorted_matching(&filters);
-    let calendar = events_to_calendar(&events.events, &filters.make_title());
-    Ok(Ics(calendar))
+    let matching = events.clone().sorted_matching(&filters);
+    Ok(Ics::from_events(
+        &matching.events,
+        &events.events,
+        &filters.make_title(),
+        &config.public_url,
+    ))
 }
 
 pub async fn flyer(
@@ -141,7 +165,17 @@ pub async fn flyer(
     Query(mut filters): Query<Filters>,
 ) -> Result<Html<String>, InternalError> {
     let events = events.sorted_matching(&filters);
-    let months = group_by_month(events.events);
+    let months = if filters.collapse_series() {
+        group_by_month(events.collapse_series())
+    } else {
+        group_by_month(
+            events
+                .events
+                .into_iter()
+                .map(|event| (event, None))
+                .collect(),
+        )
+    };
 
     filters.limit = None;
     let qr_code_link = format!(
@@ -188,7 +222,9 @@ struct FlyerTemplate {
 struct Month {
     /// The first day of the month.
     start: NaiveDate,
-    events: Vec<Event>,
+    /// Each event in the month, along with the series it stands for if it is the first of a
+    /// series of regular events.
+    events: Vec<(Event, Option<Series>)>,
 }
 
 impl Month {
@@ -206,8 +242,8 @@ impl Month {
                 events: self
                     .events
                     .iter()
-                    .filter(|event| event.time.start_date() == day)
-                    .cloned()
+                    .filter(|(event, _)| event.time.start_date() == day)
+                    .map(|(event, _)| event.clone())
                     .collect(),
             });
         }
@@ -215,29 +251,32 @@ impl Month {
     }
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 struct Day {
     day_of_month: Option<u32>,
     events: Vec<Event>,
 }
 
-/// Given a list of events sorted in ascending order of start date, groups them by starting month.
-fn group_by_month(events: Vec<Event>) -> Vec<Month> {
+/// Groups the given events by starting month, in ascending order of month. Events within each
+/// month keep the order they were given in.
+fn group_by_month(mut events: Vec<(Event, Option<Series>)>) -> Vec<Month> {
+    events.sort_by_key(|(event, _)| (event.start_year(), event.start_month()));
+
     let mut months = vec![];
     let mut month = Month {
         start: NaiveDate::MIN,
         events: vec![],
     };
-    for event in events {
+    for (event, series) in events {
         if event.start_year() == month.start.year() && event.start_month() == month.start.month() {
-            month.events.push(event);
+            month.events.push((event, series));
         } else {
             if !month.events.is_empty() {
                 months.push(month);
             }
             month = Month {
                 start: NaiveDate::from_ymd_opt(event.start_year(), event.start_month(), 1).unwrap(),
-                events: vec![event],
+                events: vec![(event, series)],
             };
         }
     }
@@ -13,11 +13,25 @@
 // limitations under the License.
 
 use crate::{
+    config::Config,
     errors::InternalError,
-    model::events::{Events, Organisation},
+    icalendar::Ics,
+    model::{
+        event::Event,
+        events::{Events, Organisation},
+        filters::{DateFilter, Filters},
+        organisations::OrganisationDetails,
+        registries::Registries,
+        status::Status,
+    },
+    util::slugify,
 };
 use askama::Template;
-use axum::response::Html;
+use axum::{
+    extract::{Path, State},
+    response::Html,
+};
+use std::{collections::HashSet, sync::Arc};
 
 pub async fn organisations(events: Events) -> Result<Html<String>, InternalError> {
     let organisations = events.organisations();
@@ -25,8 +39,93 @@ pub async fn organisations(events: Events) -> Result<Html<String>, InternalError
     Ok(Html(template.render()?))
 }
 
+pub async fn organisation(
+    events: Events,
+    registries: Registries,
+    Path(slug): Path<String>,
+) -> Result<Html<String>, InternalError> {
+    let name = organisation_name(&events, &registries, &slug).ok_or(InternalError::NotFound)?;
+    let details = registries.organisations.get(&name).cloned();
+    let upcoming = events
+        .clone()
+        .sorted_matching(&Filters {
+            organisation: Some(name.clone()),
+            ..Default::default()
+        })
+        .events;
+    let mut past = events
+        .sorted_matching(&Filters {
+            date: DateFilter::Past,
+            organisation: Some(name.clone()),
+            ..Default::default()
+        })
+        .events;
+    // Show the most recent first.
+    past.reverse();
+    let template = OrganisationTemplate {
+        slug,
+        name,
+        details,
+        upcoming,
+        past,
+    };
+    Ok(Html(template.render()?))
+}
+
+pub async fn organisation_ics(
+    State(config): State<Arc<Config>>,
+    events: Events,
+    registries: Registries,
+    Path(slug): Path<String>,
+) -> Result<Ics, InternalError> {
+    let name = organisation_name(&events, &registries, &slug).ok_or(InternalError::NotFound)?;
+    let matching = events.clone().sorted_matching(&Filters {
+        organisation: Some(name.clone()),
+        exclude_status: HashSet::from([Status::Cancelled]),
+        ..Default::default()
+    });
+    Ok(Ics::from_events(
+        &matching.events,
+        &events.events,
+        &name,
+        &config.public_url,
+    ))
+}
+
+/// Returns the name of the organisation with the given slug, either from the registry or from any
+/// event.
+fn organisation_name(events: &Events, registries: &Registries, slug: &str) -> Option<String> {
+    registries
+        .organisations
+        .organisations
+        .iter()
+        .map(|organisation| &organisation.name)
+        .chain(
+            events
+                .events
+                .iter()
+                .filter_map(|event| event.organisation.as_ref()),
+        )
+        .find(|name| slugify(name) == slug)
+        .cloned()
+}
+
 #[derive(Template)]
 #[template(path = "organisations.html")]
 struct OrganisationsTemplate {
     organisations: Vec<Organisation>,
 }
+
+#[derive(Template)]
+#[template(path = "organisation.html")]
+struct OrganisationTemplate {
+    slug: String,
+    name: String,
+    details: Option<OrganisationDetails>,
+    upcoming: Vec<Event>,
+    past: Vec<Event>,
+}
+
+mod filters {
+    pub use crate::util::slug;
+}
@@ -0,0 +1,255 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    errors::InternalError,
+    icalendar::Ics,
+    model::{
+        dancestyle::DanceStyle,
+        event::Event,
+        events::Events,
+        filters::{DateFilter, Filters},
+        performers::Performer,
+        registries::Registries,
+        status::Status,
+    },
+    util::slugify,
+};
+use askama::Template;
+use axum::response::Html;
+use std::collections::{BTreeSet, HashSet};
+
+/// Whether a profile page is for a band or a caller.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PerformerKind {
+    Band,
+    Caller,
+}
+
+impl PerformerKind {
+    /// The name of the kind, as used in URLs and query parameters.
+    fn tag(self) -> &'static str {
+        match self {
+            Self::Band => "band",
+            Self::Caller => "caller",
+        }
+    }
+
+    fn known(self, registries: &Registries) -> &[Performer] {
+        match self {
+            Self::Band => &registries.bands.bands,
+            Self::Caller => &registries.callers.callers,
+        }
+    }
+
+    fn names(self, event: &Event) -> &[String] {
+        match self {
+            Self::Band => &event.bands,
+            Self::Caller => &event.callers,
+        }
+    }
+
+    /// Returns filters for events with the given performer, within the given date range.
+    fn filters(self, name: &str, date: DateFilter) -> Filters {
+        match self {
+            Self::Band => Filters {
+                date,
+                band: Some(name.to_owned()),
+                ..Default::default()
+            },
+            Self::Caller => Filters {
+                date,
+                caller: Some(name.to_owned()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Renders the profile page for the band or caller with the given slug.
+pub fn page(
+    kind: PerformerKind,
+    events: Events,
+    registries: &Registries,
+    slug: String,
+) -> Result<Html<String>, InternalError> {
+    let name = performer_name(kind, &events, registries, &slug).ok_or(InternalError::NotFound)?;
+    let details = kind
+        .known(registries)
+        .iter()
+        .find(|performer| performer.name == name)
+        .cloned();
+    let upcoming = events
+        .clone()
+        .sorted_matching(&kind.filters(&name, DateFilter::Future))
+        .events;
+    let past = events
+        .sorted_matching(&kind.filters(&name, DateFilter::Past))
+        .events;
+
+    let countries = upcoming
+        .iter()
+        .chain(&past)
+        .map(|event| event.country.clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let styles = upcoming
+        .iter()
+        .chain(&past)
+        .flat_map(|event| event.styles.iter().copied())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let template = PerformerTemplate {
+        kind,
+        slug,
+        name,
+        details,
+        upcoming,
+        past_years: group_by_year(past),
+        countries,
+        styles,
+    };
+    Ok(Html(template.render()?))
+}
+
+/// Returns an iCalendar feed of upcoming events for the band or caller with the given slug.
+pub fn ics(
+    kind: PerformerKind,
+    events: Events,
+    registries: &Registries,
+    slug: &str,
+    public_url: &str,
+) -> Result<Ics, InternalError> {
+    let name = performer_name(kind, &events, registries, slug).ok_or(InternalError::NotFound)?;
+    let matching = events.clone().sorted_matching(&Filters {
+        exclude_status: HashSet::from([Status::Cancelled]),
+        ..kind.filters(&name, DateFilter::Future)
+    });
+    Ok(Ics::from_events(
+        &matching.events,
+        &events.events,
+        &name,
+        public_url,
+    ))
+}
+
+/// Returns the name of the band or caller with the given slug, either from the registry or from
+/// any event.
+fn performer_name(
+    kind: PerformerKind,
+    events: &Events,
+    registries: &Registries,
+    slug: &str,
+) -> Option<String> {
+    kind.known(registries)
+        .iter()
+        .map(|performer| &performer.name)
+        .chain(events.events.iter().flat_map(|event| kind.names(event)))
+        .find(|name| slugify(name) == slug)
+        .cloned()
+}
+
+/// Groups the given events in chronological order by the year they start, with the most recent
+/// year and event first.
+fn group_by_year(events: Vec<Event>) -> Vec<YearEvents> {
+    let mut years: Vec<YearEvents> = Vec::new();
+    for event in events.into_iter().rev() {
+        let year = event.start_year();
+        match years.last_mut() {
+            Some(last) if last.year == year => last.events.push(event),
+            _ => years.push(YearEvents {
+                year,
+                events: vec![event],
+            }),
+        }
+    }
+    years
+}
+
+/// The events in a single year.
+#[derive(Clone, Debug, PartialEq)]
+struct YearEvents {
+    year: i32,
+    events: Vec<Event>,
+}
+
+#[derive(Template)]
+#[template(path = "performer.html")]
+struct PerformerTemplate {
+    kind: PerformerKind,
+    slug: String,
+    name: String,
+    details: Option<Performer>,
+    upcoming: Vec<Event>,
+    past_years: Vec<YearEvents>,
+    countries: Vec<String>,
+    styles: Vec<DanceStyle>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_past_events_by_year() {
+        let events = Events::load_str(
+            r#"
+events:
+  - name: First
+    start_date: 2024-11-02
+    end_date: 2024-11-02
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+  - name: Second
+    start_date: 2025-03-08
+    end_date: 2025-03-08
+    country: France
+    city: Paris
+    styles: [balfolk]
+    social: true
+  - name: Third
+    start_date: 2025-06-14
+    end_date: 2025-06-14
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+"#,
+        )
+        .unwrap();
+
+        let years = group_by_year(events.events);
+        let summary: Vec<(i32, Vec<&str>)> = years
+            .iter()
+            .map(|year| {
+                (
+                    year.year,
+                    year.events
+                        .iter()
+                        .map(|event| event.name.as_str())
+                        .collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(2025, vec!["Third", "Second"]), (2024, vec!["First"])]
+        );
+    }
+}
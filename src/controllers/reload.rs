@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{config::Config, errors::InternalError, model::events::Events};
+use crate::{
+    config::Config,
+    errors::InternalError,
+    model::{events::Events, registries::Registries},
+};
 use axum::extract::{Form, State};
 use log::info;
 use serde::{Deserialize, Serialize};
@@ -20,6 +24,7 @@ use std::sync::{Arc, Mutex};
 
 pub async fn reload(
     State(events): State<Arc<Mutex<Events>>>,
+    State(registries): State<Arc<Mutex<Registries>>>,
     State(config): State<Arc<Config>>,
     Form(request): Form<ReloadRequest>,
 ) -> Result<String, InternalError> {
@@ -27,10 +32,13 @@ pub async fn reload(
         return Err(InternalError::Unauthorised);
     }
 
-    let new_events = Events::load_events(&config.events)
+    let new_events = Events::load_events_with_window(&config.events, config.recurrence_window)
         .await
         .map_err(InternalError::Internal)?;
+    let new_registries =
+        Registries::load_for_path(&config.events).map_err(InternalError::Internal)?;
 
+    *registries.lock().unwrap() = new_registries;
     let mut events = events.lock().unwrap();
     *events = new_events;
 
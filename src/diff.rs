@@ -25,7 +25,7 @@ pub fn diff_markdown(events_a: Vec<Event>, events_b: Vec<Event>) -> Result<Strin
     Ok(html)
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 struct DiffResult {
     /// The boolean is false if the event is only in the first list, true if it is only in the
     /// second.
@@ -76,11 +76,14 @@ struct DiffTemplate {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::{dancestyle::DanceStyle, event::EventTime};
+    use crate::model::{
+        attendance::AttendanceMode, dancestyle::DanceStyle, event::EventTime, status::Status,
+    };
     use chrono::NaiveDate;
 
     fn test_event(name: &str, date: NaiveDate) -> Event {
         Event {
+            id: None,
             name: name.to_string(),
             details: None,
             links: vec![],
@@ -88,17 +91,35 @@ mod tests {
                 start_date: date,
                 end_date: date,
             },
+            timezone: None,
             country: "Country".to_string(),
             state: None,
             city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::Contra],
             workshop: false,
             social: true,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         }
     }
@@ -27,6 +27,7 @@ use std::{
 #[derive(Debug)]
 pub enum InternalError {
     Internal(Report),
+    NotFound,
     Unauthorised,
 }
 
@@ -43,6 +44,7 @@ impl IntoResponse for InternalError {
                 warn!("Internal error: {}", report);
                 internal_error_response(report)
             }
+            Self::NotFound => StatusCode::NOT_FOUND.into_response(),
             Self::Unauthorised => StatusCode::UNAUTHORIZED.into_response(),
         }
     }
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AppState, errors::InternalError, model::events::Events};
+use crate::{
+    AppState,
+    errors::InternalError,
+    model::{events::Events, registries::Registries},
+};
 use axum::{extract::FromRequestParts, http::request::Parts};
 
 impl FromRequestParts<AppState> for Events {
@@ -25,3 +29,14 @@ impl FromRequestParts<AppState> for Events {
         Ok(state.events.lock().unwrap().clone())
     }
 }
+
+impl FromRequestParts<AppState> for Registries {
+    type Rejection = InternalError;
+
+    async fn from_request_parts(
+        _parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        Ok(state.registries.lock().unwrap().clone())
+    }
+}
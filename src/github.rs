@@ -1,7 +1,7 @@
 use crate::{
     config::GitHubConfig,
     errors::InternalError,
-    model::{event::Event, events::Events},
+    model::{event::Event, events::Events, registries::Registries},
 };
 use eyre::{OptionExt, eyre};
 use jsonwebtoken::EncodingKey;
@@ -175,11 +175,15 @@ pub async fn add_event_to_file(
 
 /// Creates a PR to edit the given event in the given file.
 ///
+/// The original and new events are as shown on the website, with details filled in from the given
+/// registries.
+///
 /// Returns the URL of the new PR.
 pub async fn edit_event_in_file(
     filename: &str,
     original_event: &Event,
     new_event: Event,
+    registries: &Registries,
     email: Option<&str>,
     config: &GitHubConfig,
 ) -> Result<Url, InternalError> {
@@ -211,12 +215,9 @@ pub async fn edit_event_in_file(
     let mut events = Events::load_str(&existing_content).map_err(InternalError::Internal)?;
 
     // Replace the original event with the new version and sort.
-    for e in &mut events.events {
-        if e == original_event {
-            *e = new_event;
-            break;
-        }
-    }
+    events
+        .replace_prepared(original_event, new_event, registries)
+        .map_err(InternalError::Internal)?;
     events.sort();
     let new_content = events.to_yaml_string().map_err(InternalError::Internal)?;
 
@@ -271,8 +272,21 @@ pub fn to_safe_filename(s: &str) -> String {
     filename
 }
 
+/// Returns the path of the file with the given name for events in the given country, relative to
+/// the root of the events repository.
+///
+/// Online-only events may not have a country, so they go in an `online` directory instead.
+pub fn file_for_country(country: &str, filename: &str) -> String {
+    let directory = if country.is_empty() {
+        "online".to_owned()
+    } else {
+        to_safe_filename(country)
+    };
+    format!("events/{directory}/{filename}")
+}
+
 /// Value returned by [`choose_file_for_event`] when the event is a duplicate of an existing one.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct DuplicateEvent {
     pub existing: Event,
     pub merged: Event,
@@ -304,11 +318,13 @@ pub fn choose_file_for_event(events: &Events, event: &Event) -> Result<String, D
         organisation_files.iter().next().unwrap().to_owned()
     } else if city_files.len() == 1 {
         city_files.iter().next().unwrap().to_owned()
+    } else if event.country.is_empty() {
+        // Online-only events added with the form may not have a country or city.
+        "events/online.yaml".to_owned()
     } else {
-        format!(
-            "events/{}/{}.yaml",
-            to_safe_filename(&event.country),
-            to_safe_filename(&event.city),
+        file_for_country(
+            &event.country,
+            &format!("{}.yaml", to_safe_filename(&event.city)),
         )
     };
 
@@ -331,4 +347,16 @@ mod tests {
             "weird_characters"
         )
     }
+
+    #[test]
+    fn files_for_countries() {
+        assert_eq!(
+            file_for_country("New Zealand", "dunedin.yaml"),
+            "events/new_zealand/dunedin.yaml"
+        );
+        assert_eq!(
+            file_for_country("", "plugevents.yaml"),
+            "events/online/plugevents.yaml"
+        );
+    }
 }
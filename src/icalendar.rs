@@ -1,25 +1,296 @@
-use crate::model::event::{Event, EventTime};
+use crate::model::{
+    attendance::AttendanceMode,
+    event::{Event, EventTime},
+    recurrence::{instance, instance_time},
+    status::Status,
+};
 use axum::{
     body::Body,
     http::{HeaderValue, header},
     response::{IntoResponse, Response},
 };
-use chrono::Utc;
-use icalendar::{Calendar, Component, EventLike, EventStatus};
-use std::fmt::Write;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Offset, TimeDelta, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use icalendar::{
+    Calendar, CalendarDateTime, Component, DatePerhapsTime, EventLike, EventStatus, Property,
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{self, Display, Formatter, Write},
+    iter::once,
+    sync::Arc,
+};
+use url::Url;
+
+/// The domain of the main site, used to make globally unique event UIDs.
+const SITE_DOMAIN: &str = "folkdance.page";
+
+/// How often calendar clients should refresh subscribed calendars.
+const REFRESH_INTERVAL: TimeDelta = TimeDelta::hours(6);
+
+/// Makes a calendar with the given name containing the given events, which are some or all of
+/// `all_events`, linking to the site at the given public URL.
+///
+/// If every loaded instance of a recurring event is included, they are collapsed back into a single
+/// event with an `RRULE`, followed by an event for each instance with overridden details. Otherwise
+/// only the included instances are given, each identified by its `RECURRENCE-ID`.
+pub fn events_to_calendar(
+    events: &[Event],
+    all_events: &[Event],
+    name: &str,
+    public_url: &str,
+) -> Calendar {
+    let all_counts = count_instances(all_events);
+    let counts = count_instances(events);
+    let mut calendar = Calendar::new();
+    let mut series_added = HashSet::new();
+    for event in events {
+        if let Some(series) = &event.series {
+            let key = Arc::as_ptr(series);
+            let (count, last) = counts[&key];
+            if all_counts.get(&key).map(|(all_count, _)| *all_count) != Some(count) {
+                calendar.push(instance_to_event(event, series, public_url));
+            } else if series_added.insert(key) {
+                for calendar_event in series_to_events(series, last, public_url) {
+                    calendar.push(calendar_event);
+                }
+            }
+        } else {
+            calendar.push(event_to_event(event, public_url));
+        }
+    }
+    calendar.name(name).ttl(&REFRESH_INTERVAL).done()
+}
+
+/// Returns the number of instances of each recurring event among the given events, along with the
+/// time of the last one.
+///
+/// Instances of the same recurring event share it, so they are keyed by its address.
+fn count_instances(events: &[Event]) -> HashMap<*const Event, (usize, &EventTime)> {
+    let mut counts: HashMap<_, (usize, &EventTime)> = HashMap::new();
+    for event in events {
+        if let Some(series) = &event.series {
+            counts
+                .entry(Arc::as_ptr(series))
+                .and_modify(|(count, last)| {
+                    *count += 1;
+                    if event.time.start_time_sort_key() > last.start_time_sort_key() {
+                        *last = &event.time;
+                    }
+                })
+                .or_insert((1, &event.time));
+        }
+    }
+    counts
+}
+
+/// Returns an event for the given recurring event with its recurrence rule and exceptions, followed
+/// by an event for each instance which has overridden details.
+///
+/// The rule is ended at the given last instance, so that the calendar only has the instances which
+/// were loaded, and so that the timezones cover them.
+fn series_to_events(
+    series: &Arc<Event>,
+    last: &EventTime,
+    public_url: &str,
+) -> Vec<icalendar::Event> {
+    let mut calendar_event = event_to_event(series, public_url);
+    let Some(recurrence) = &series.recurrence else {
+        return vec![calendar_event];
+    };
+    calendar_event.add_property("RRULE", rrule_until(&recurrence.rrule, last));
+    for date in &recurrence.except {
+        if let Some(time) = instance_time(series, *date) {
+            calendar_event.append_multi_property(
+                start_date_perhaps_time(&time, series).to_property("EXDATE"),
+            );
+        }
+    }
+
+    let mut calendar_events = vec![calendar_event];
+    for instance_override in &recurrence.overrides {
+        if let Some(instance) = instance(series, instance_override.date) {
+            calendar_events.push(instance_to_event(&instance, series, public_url));
+        }
+    }
+    calendar_events
+}
 
-pub fn events_to_calendar(events: &[Event], name: &str) -> Calendar {
-    events
+/// Returns an event for the given instance of the given recurring event, identified as part of the
+/// series.
+fn instance_to_event(instance: &Event, series: &Event, public_url: &str) -> icalendar::Event {
+    let mut calendar_event = event_to_event(instance, public_url);
+    calendar_event
+        .uid(&format!("{}@{SITE_DOMAIN}", series.id_or_hash()))
+        .recurrence_id(start_date_perhaps_time(&instance.time, series));
+    calendar_event
+}
+
+/// Returns the given recurrence rule with any `COUNT` or `UNTIL` replaced by an `UNTIL` at the start
+/// of the given instance.
+fn rrule_until(rrule: &str, last: &EventTime) -> String {
+    let until = match last {
+        EventTime::DateOnly { start_date, .. } => start_date.format("%Y%m%d").to_string(),
+        EventTime::DateTime { start, .. } => start
+            .with_timezone(&Utc)
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string(),
+    };
+    rrule
+        .split(';')
+        .filter(|part| {
+            let part = part.to_ascii_uppercase();
+            !part.starts_with("COUNT=") && !part.starts_with("UNTIL=")
+        })
+        .map(ToOwned::to_owned)
+        .chain(once(format!("UNTIL={until}")))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Returns the start of the given time, in the timezone of the given event if it has one, e.g. to
+/// identify an instance of a recurring event.
+fn start_date_perhaps_time(time: &EventTime, event: &Event) -> DatePerhapsTime {
+    match (time, event.timezone) {
+        (EventTime::DateOnly { start_date, .. }, _) => DatePerhapsTime::Date(*start_date),
+        (EventTime::DateTime { start, .. }, Some(timezone)) => CalendarDateTime::WithTimezone {
+            date_time: start.with_timezone(&timezone).naive_local(),
+            tzid: timezone.name().to_owned(),
+        }
+        .into(),
+        (EventTime::DateTime { start, .. }, None) => start.with_timezone(&Utc).into(),
+    }
+}
+
+/// Returns a `VTIMEZONE` component for each timezone used by the given events, covering the period
+/// of those events.
+fn vtimezones(events: &[Event]) -> String {
+    let mut ranges: BTreeMap<&str, (Tz, DateTime<Utc>, DateTime<Utc>)> = BTreeMap::new();
+    // Recurring events are given from the start of their series, so include that too.
+    for event in events
         .iter()
-        .map(event_to_event)
-        .collect::<Calendar>()
-        .name(name)
-        .done()
+        .flat_map(|event| once(event).chain(event.series.as_deref()))
+    {
+        if let (Some(timezone), EventTime::DateTime { start, end }) = (event.timezone, &event.time)
+        {
+            let start = start.with_timezone(&Utc);
+            let end = end.with_timezone(&Utc);
+            ranges
+                .entry(timezone.name())
+                .and_modify(|(_, from, to)| {
+                    *from = start.min(*from);
+                    *to = end.max(*to);
+                })
+                .or_insert((timezone, start, end));
+        }
+    }
+    ranges
+        .into_values()
+        .map(|(timezone, from, to)| {
+            // Start at the beginning of the year so that the component includes the most recent
+            // transition before the first event.
+            let from = NaiveDate::from_ymd_opt(from.year(), 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc();
+            vtimezone(timezone, from, to)
+        })
+        .collect()
+}
+
+/// Returns a `VTIMEZONE` component for the given timezone, with an observance for each change in
+/// UTC offset between the given instants.
+fn vtimezone(timezone: Tz, from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    let mut vtimezone = format!("BEGIN:VTIMEZONE\r\nTZID:{}\r\n", timezone.name());
+    let mut offset = timezone.offset_from_utc_datetime(&from.naive_utc());
+    write_observance(&mut vtimezone, from, offset.fix(), offset);
+
+    let mut time = from;
+    while time < to {
+        let next = time + TimeDelta::days(1);
+        let next_offset = timezone.offset_from_utc_datetime(&next.naive_utc());
+        if next_offset.fix() != offset.fix() {
+            // Binary search for the instant of the transition.
+            let (mut before, mut after) = (time, next);
+            while after - before > TimeDelta::seconds(1) {
+                let middle = before + (after - before) / 2;
+                if timezone.offset_from_utc_datetime(&middle.naive_utc()).fix() == offset.fix() {
+                    before = middle;
+                } else {
+                    after = middle;
+                }
+            }
+            write_observance(&mut vtimezone, after, offset.fix(), next_offset);
+            offset = next_offset;
+        }
+        time = next;
+    }
+
+    vtimezone += "END:VTIMEZONE\r\n";
+    vtimezone
+}
+
+/// Writes a `STANDARD` or `DAYLIGHT` observance for a change from the given offset to the given
+/// new offset at the given instant.
+fn write_observance(
+    vtimezone: &mut String,
+    onset: DateTime<Utc>,
+    offset_from: FixedOffset,
+    offset_to: <Tz as TimeZone>::Offset,
+) {
+    let kind = if offset_to.dst_offset().is_zero() {
+        "STANDARD"
+    } else {
+        "DAYLIGHT"
+    };
+    // The onset is given in local time before the change.
+    let local_onset = onset.with_timezone(&offset_from).naive_local();
+    write!(
+        vtimezone,
+        "BEGIN:{kind}\r\nDTSTART:{}\r\nTZOFFSETFROM:{}\r\nTZOFFSETTO:{}\r\n",
+        local_onset.format("%Y%m%dT%H%M%S"),
+        format_offset(offset_from),
+        format_offset(offset_to.fix()),
+    )
+    .unwrap();
+    if let Some(abbreviation) = offset_to.abbreviation() {
+        write!(vtimezone, "TZNAME:{abbreviation}\r\n").unwrap();
+    }
+    write!(vtimezone, "END:{kind}\r\n").unwrap();
 }
 
-fn event_to_event(event: &Event) -> icalendar::Event {
+/// Formats a UTC offset as used by iCalendar, like "+0100" or "-0330".
+fn format_offset(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let seconds = seconds.abs();
+    if seconds % 60 == 0 {
+        format!("{sign}{:02}{:02}", seconds / 3600, seconds / 60 % 60)
+    } else {
+        format!(
+            "{sign}{:02}{:02}{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+/// Returns a plain text description of the event, including the details, styles, schedule, bands,
+/// callers, teachers, level and other attributes, price, stream URL and links, and where to find a
+/// rescheduled event on the site at the given public URL.
+pub fn description(event: &Event, public_url: &str) -> String {
     let mut description = String::new();
-    if let Some(details) = &event.details {
+    if let Some(rescheduled_to) = &event.rescheduled_to {
+        writeln!(
+            description,
+            "Rescheduled to {}/event/{rescheduled_to}",
+            public_url.trim_end_matches('/')
+        )
+        .unwrap();
+    }
+    if let Some(details) = event.details_text() {
         writeln!(description, "{details}").unwrap();
     }
     writeln!(
@@ -44,19 +315,38 @@ fn event_to_event(event: &Event) -> icalendar::Event {
         }
     )
     .unwrap();
+    if !event.sessions.is_empty() {
+        writeln!(description, "Schedule:").unwrap();
+        for session in &event.sessions {
+            writeln!(description, "- {}", session.summary()).unwrap();
+        }
+    }
     if !event.bands.is_empty() {
         writeln!(description, "Bands: {}", event.bands.join(", ")).unwrap();
     }
     if !event.callers.is_empty() {
         writeln!(description, "Callers: {}", event.callers.join(", ")).unwrap();
     }
+    if !event.teachers.is_empty() {
+        writeln!(description, "Teachers: {}", event.teachers.join(", ")).unwrap();
+    }
+    let attribute_labels = event.attribute_labels();
+    if !attribute_labels.is_empty() {
+        writeln!(description, "Good to know: {}", attribute_labels.join(", ")).unwrap();
+    }
     if let Some(price) = &event.price {
         writeln!(description, "Price: {price}").unwrap();
     }
+    if let Some(stream_url) = &event.stream_url {
+        writeln!(description, "Join online: {stream_url}").unwrap();
+    }
     for link in &event.links {
         writeln!(description, "{link}").unwrap();
     }
+    description
+}
 
+fn event_to_event(event: &Event, public_url: &str) -> icalendar::Event {
     let categories = event
         .styles
         .iter()
@@ -65,14 +355,23 @@ fn event_to_event(event: &Event) -> icalendar::Event {
         .join(",");
 
     let mut calendar_event = icalendar::Event::new();
+    let summary = if event.status.is_scheduled() {
+        event.name.clone()
+    } else {
+        format!(
+            "{}: {}",
+            event.status.to_string().to_uppercase(),
+            event.name
+        )
+    };
     calendar_event
-        .summary(&event.name)
-        .location(&format!("{}, {}", event.city, event.country))
-        .description(&description)
-        .status(if event.cancelled {
-            EventStatus::Cancelled
-        } else {
-            EventStatus::Confirmed
+        .summary(&summary)
+        .location(&location(event))
+        .description(&description(event, public_url))
+        .status(match event.status {
+            Status::Cancelled | Status::Postponed | Status::Rescheduled => EventStatus::Cancelled,
+            Status::Tentative => EventStatus::Tentative,
+            Status::Scheduled | Status::SoldOut => EventStatus::Confirmed,
         })
         .add_property("CATEGORIES", &categories);
     match event.time {
@@ -86,23 +385,132 @@ fn event_to_event(event: &Event) -> icalendar::Event {
                 .ends(end_date.succ_opt().unwrap());
         }
         EventTime::DateTime { start, end } => {
-            calendar_event
-                .starts(start.with_timezone(&Utc))
-                .ends(end.with_timezone(&Utc));
+            if let Some(timezone) = event.timezone {
+                calendar_event
+                    .starts(CalendarDateTime::WithTimezone {
+                        date_time: start.with_timezone(&timezone).naive_local(),
+                        tzid: timezone.name().to_owned(),
+                    })
+                    .ends(CalendarDateTime::WithTimezone {
+                        date_time: end.with_timezone(&timezone).naive_local(),
+                        tzid: timezone.name().to_owned(),
+                    });
+            } else {
+                calendar_event
+                    .starts(start.with_timezone(&Utc))
+                    .ends(end.with_timezone(&Utc));
+            }
         }
     }
+    // Use the same UID every time so that calendar clients update the event rather than adding a
+    // duplicate.
+    calendar_event.uid(&format!("{}@{SITE_DOMAIN}", event.id_or_hash()));
+    // Use a fixed timestamp rather than the current time, so that the calendar only changes when
+    // the events do. When events were last modified isn't known, so `LAST-MODIFIED` is left out.
+    calendar_event.timestamp(DateTime::UNIX_EPOCH);
+    if let Some(link) = event.main_link() {
+        calendar_event.url(link);
+    }
+    if let Some(organisation) = &event.organisation {
+        calendar_event.append_property(organizer(organisation, public_url));
+    }
+    if let Some((latitude, longitude)) = event.coordinates() {
+        calendar_event.add_property("GEO", format!("{latitude};{longitude}"));
+    }
     for link in &event.links {
         calendar_event.add_multi_property("ATTACH", link);
     }
     calendar_event
 }
 
+/// Returns the venue and address of the event as much as they are known, followed by the city and
+/// country. Online-only events are given as their stream URL if known, and hybrid events note that
+/// they are also online.
+pub fn location(event: &Event) -> String {
+    match event.attendance {
+        AttendanceMode::Online => event.stream_url.as_deref().unwrap_or("Online").to_owned(),
+        AttendanceMode::InPerson | AttendanceMode::Hybrid => {
+            let mut parts = vec![];
+            parts.extend(event.venue.as_deref());
+            parts.extend(event.address.as_deref());
+            parts.push(&event.city);
+            parts.push(&event.country);
+            if event.attendance == AttendanceMode::Hybrid {
+                parts.push("and online");
+            }
+            parts.join(", ")
+        }
+    }
+}
+
+/// Returns an `ORGANIZER` property for the given organisation, linking to its events on the site
+/// at the given public URL.
+fn organizer(organisation: &str, public_url: &str) -> Property {
+    let url = Url::parse_with_params(
+        &format!("{}/", public_url.trim_end_matches('/')),
+        &[("organisation", organisation)],
+    )
+    .unwrap();
+    // Parameter values can't contain double quotes, but may contain other special characters if
+    // they are quoted.
+    let name = format!("\"{}\"", organisation.replace('"', "'"));
+    Property::new("ORGANIZER", url.as_str())
+        .add_parameter("CN", &name)
+        .done()
+}
+
 #[derive(Debug)]
-pub struct Ics(pub Calendar);
+pub struct Ics {
+    pub calendar: Calendar,
+    /// `VTIMEZONE` components to include in the calendar. These are kept separately because the
+    /// `icalendar` crate adds `DTSTAMP` and `UID` properties to every component, which aren't
+    /// valid for timezones.
+    pub vtimezones: String,
+}
+
+impl Ics {
+    /// Makes a calendar with the given name containing the given events, which are some or all of
+    /// `all_events`, linking to the site at the given public URL, and definitions of the timezones
+    /// they use.
+    pub fn from_events(
+        events: &[Event],
+        all_events: &[Event],
+        name: &str,
+        public_url: &str,
+    ) -> Self {
+        Self {
+            calendar: events_to_calendar(events, all_events, name, public_url),
+            vtimezones: vtimezones(events),
+        }
+    }
+}
+
+impl From<Calendar> for Ics {
+    fn from(calendar: Calendar) -> Self {
+        Self {
+            calendar,
+            vtimezones: String::new(),
+        }
+    }
+}
+
+impl Display for Ics {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let calendar = self.calendar.to_string();
+        match calendar.rfind("END:VCALENDAR") {
+            Some(end) => {
+                f.write_str(&calendar[..end])?;
+                f.write_str(&self.vtimezones)?;
+                f.write_str(&calendar[end..])
+            }
+            None => f.write_str(&calendar),
+        }
+    }
+}
 
 impl IntoResponse for Ics {
     fn into_response(self) -> Response {
-        let mut res = Response::new(Body::from(self.0.to_string()));
+        let mut res = Response::new(Body::from(self.to_string()));
         res.headers_mut().insert(
             header::CONTENT_TYPE,
             HeaderValue::from_static("text/calendar"),
@@ -114,13 +522,415 @@ impl IntoResponse for Ics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::{
+        event,
+        inclusivity::{Accessibility, Level, RoleTerms},
+        price::Price,
+        recurrence::{RecurrenceWindow, expand},
+        session::{Session, SessionKind},
+    };
     use axum::body;
-    use chrono::TimeZone;
+    use chrono::{NaiveDate, TimeZone};
+
+    const PUBLIC_URL: &str = "https://folkdance.page";
+
+    fn test_event() -> Event {
+        Event {
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            },
+            country: "UK".to_string(),
+            city: "London".to_string(),
+            ..event::test_event()
+        }
+    }
+
+    #[test]
+    fn location_city_only() {
+        let calendar_event = event_to_event(&test_event(), PUBLIC_URL);
+        assert_eq!(calendar_event.get_location(), Some("London, UK"));
+        assert_eq!(calendar_event.property_value("GEO"), None);
+    }
+
+    #[test]
+    fn location_online() {
+        let online = Event {
+            attendance: AttendanceMode::Online,
+            stream_url: Some("https://example.com/zoom".to_string()),
+            country: String::new(),
+            city: String::new(),
+            ..test_event()
+        };
+        assert_eq!(location(&online), "https://example.com/zoom");
+        assert!(
+            description(&online, PUBLIC_URL).contains("Join online: https://example.com/zoom\n")
+        );
+
+        let hybrid = Event {
+            attendance: AttendanceMode::Hybrid,
+            ..test_event()
+        };
+        assert_eq!(location(&hybrid), "London, UK, and online");
+    }
+
+    #[test]
+    fn location_venue_address_geo() {
+        let event = Event {
+            venue: Some("Cecil Sharp House".to_string()),
+            address: Some("2 Regent's Park Road, NW1 7AY".to_string()),
+            latitude: Some(51.5388),
+            longitude: Some(-0.1487),
+            ..test_event()
+        };
+        let calendar_event = event_to_event(&event, PUBLIC_URL);
+        assert_eq!(
+            calendar_event.get_location(),
+            Some("Cecil Sharp House, 2 Regent's Park Road, NW1 7AY, London, UK")
+        );
+        assert_eq!(
+            calendar_event.property_value("GEO"),
+            Some("51.5388;-0.1487")
+        );
+    }
+
+    #[test]
+    fn uid_from_id() {
+        let event = Event {
+            id: Some("8d5bd0b4-0b4a-4a4e-9f4e-2f7a4a1b1e6d".to_string()),
+            ..test_event()
+        };
+        let calendar_event = event_to_event(&event, PUBLIC_URL);
+        assert_eq!(
+            calendar_event.get_uid(),
+            Some("8d5bd0b4-0b4a-4a4e-9f4e-2f7a4a1b1e6d@folkdance.page")
+        );
+    }
+
+    #[test]
+    fn uid_deterministic_without_id() {
+        let event = test_event();
+        let uid = format!("{}@folkdance.page", event.hash_string());
+        assert_eq!(
+            event_to_event(&event, PUBLIC_URL).get_uid(),
+            Some(uid.as_str())
+        );
+        assert_eq!(
+            event_to_event(&event, PUBLIC_URL).get_uid(),
+            Some(uid.as_str())
+        );
+    }
+
+    #[test]
+    fn stable_timestamps() {
+        let events = [Event {
+            source: Some("events/uk/london.yaml".to_string()),
+            ..test_event()
+        }];
+        let calendar = Ics::from_events(&events, &events, "Test", PUBLIC_URL).to_string();
+        assert!(calendar.contains("DTSTAMP:19700101T000000Z\r\n"));
+        assert!(!calendar.contains("LAST-MODIFIED"));
+        assert_eq!(
+            Ics::from_events(&events, &events, "Test", PUBLIC_URL).to_string(),
+            calendar
+        );
+    }
+
+    #[test]
+    fn url_and_organizer() {
+        let calendar_event = event_to_event(&test_event(), PUBLIC_URL);
+        assert_eq!(calendar_event.get_url(), None);
+        assert_eq!(calendar_event.property_value("ORGANIZER"), None);
+
+        let event = Event {
+            links: vec![
+                "https://example.com/bal".to_string(),
+                "https://example.com/other".to_string(),
+            ],
+            organisation: Some("Folk; Dance, \"Club\"".to_string()),
+            ..test_event()
+        };
+        let calendar_event = event_to_event(&event, PUBLIC_URL);
+        assert_eq!(calendar_event.get_url(), Some("https://example.com/bal"));
+        let calendar = Calendar::new().push(calendar_event).done().to_string();
+        assert!(calendar.contains(
+            "ORGANIZER;CN=\"Folk; Dance, 'Club'\":https://folkdance.page/?organisation=Fol\r\n \
+             k%3B+Dance%2C+%22Club%22\r\n"
+        ));
+    }
+
+    #[test]
+    fn organizer_uses_public_url() {
+        let organizer = organizer("Folk Club", "http://localhost:3002/");
+        assert_eq!(
+            organizer.value(),
+            "http://localhost:3002/?organisation=Folk+Club"
+        );
+    }
+
+    #[test]
+    fn structured_price_description() {
+        let event = Event {
+            price: Some(Price {
+                note: Some("concessions £6".to_string()),
+                ..Price::range(8.0, 10.0, "GBP")
+            }),
+            ..test_event()
+        };
+        assert!(description(&event, PUBLIC_URL).contains("Price: £8-£10, concessions £6\n"));
+    }
+
+    #[test]
+    fn markdown_details_description() {
+        let event = Event {
+            details: Some(
+                "Bal with **live music**.\n\n[Tickets](https://example.com/)".to_string(),
+            ),
+            ..test_event()
+        };
+        assert!(
+            description(&event, PUBLIC_URL)
+                .starts_with("Bal with live music.\nTickets (https://example.com/)\nDance styles:")
+        );
+    }
+
+    #[test]
+    fn sessions_description() {
+        let event = Event {
+            sessions: vec![Session {
+                name: None,
+                kind: SessionKind::Concert,
+                time: EventTime::DateOnly {
+                    start_date: NaiveDate::from_ymd_opt(2022, 2, 3).unwrap(),
+                    end_date: NaiveDate::from_ymd_opt(2022, 2, 3).unwrap(),
+                },
+                bands: vec!["Naragonia".to_string()],
+                callers: vec![],
+                teachers: vec![],
+            }],
+            ..test_event()
+        };
+        assert!(
+            description(&event, PUBLIC_URL)
+                .contains("Schedule:\n- Thu 3 concert, bands: Naragonia\n")
+        );
+    }
+
+    #[test]
+    fn attributes_description() {
+        assert!(!description(&test_event(), PUBLIC_URL).contains("Good to know"));
+
+        let event = Event {
+            level: Some(Level::Beginner),
+            role_terms: Some(RoleTerms::GenderFree),
+            no_partner_needed: true,
+            accessibility: vec![Accessibility::StepFree],
+            ..test_event()
+        };
+        assert!(description(&event, PUBLIC_URL).contains(
+            "Good to know: beginner, gender-free roles, no partner needed, step-free access\n"
+        ));
+    }
+
+    #[test]
+    fn status() {
+        let tentative = Event {
+            status: Status::Tentative,
+            ..test_event()
+        };
+        let events = [tentative];
+        let calendar = events_to_calendar(&events, &events, "Events", PUBLIC_URL).to_string();
+        assert!(calendar.contains("STATUS:TENTATIVE\r\n"));
+        assert!(calendar.contains("SUMMARY:TENTATIVE: Test event\r\n"));
+
+        let rescheduled = Event {
+            status: Status::Rescheduled,
+            rescheduled_to: Some("new-id".to_string()),
+            ..test_event()
+        };
+        let events = std::slice::from_ref(&rescheduled);
+        let calendar = events_to_calendar(events, events, "Events", PUBLIC_URL).to_string();
+        assert!(calendar.contains("STATUS:CANCELLED\r\n"));
+        assert!(calendar.contains("SUMMARY:RESCHEDULED: Test event\r\n"));
+        assert!(
+            description(&rescheduled, PUBLIC_URL)
+                .starts_with("Rescheduled to https://folkdance.page/event/new-id\n")
+        );
+        assert!(
+            description(&rescheduled, "http://localhost:3002/")
+                .starts_with("Rescheduled to http://localhost:3002/event/new-id\n")
+        );
+    }
+
+    #[test]
+    fn calendar_name_and_ttl() {
+        let events = [test_event()];
+        let calendar = events_to_calendar(&events, &events, "Balfolk events", PUBLIC_URL);
+        assert_eq!(calendar.get_name(), Some("Balfolk events"));
+        assert_eq!(
+            calendar.property_value("X-WR-CALNAME"),
+            Some("Balfolk events")
+        );
+        assert_eq!(calendar.get_ttl(), Some(TimeDelta::hours(6)));
+        let calendar = calendar.to_string();
+        assert!(calendar.contains("REFRESH-INTERVAL;VALUE=DURATION:PT21600S\r\n"));
+        assert!(calendar.contains("X-PUBLISHED-TTL:PT21600S\r\n"));
+    }
+
+    #[test]
+    fn timezone() {
+        let start = FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(2025, 3, 29, 19, 0, 0)
+            .unwrap();
+        let end = FixedOffset::east_opt(3600)
+            .unwrap()
+            .with_ymd_and_hms(2025, 3, 30, 16, 0, 0)
+            .unwrap();
+        let event = Event {
+            time: EventTime::DateTime { start, end },
+            timezone: Some(Tz::Europe__London),
+            ..test_event()
+        };
+        let events = [event];
+        let calendar = Ics::from_events(&events, &events, "Test", PUBLIC_URL).to_string();
+        assert!(calendar.contains(
+            "\
+BEGIN:VTIMEZONE\r
+TZID:Europe/London\r
+BEGIN:STANDARD\r
+DTSTART:20250101T000000\r
+TZOFFSETFROM:+0000\r
+TZOFFSETTO:+0000\r
+TZNAME:GMT\r
+END:STANDARD\r
+BEGIN:DAYLIGHT\r
+DTSTART:20250330T010000\r
+TZOFFSETFROM:+0000\r
+TZOFFSETTO:+0100\r
+TZNAME:BST\r
+END:DAYLIGHT\r
+END:VTIMEZONE\r
+END:VCALENDAR\r
+"
+        ));
+        assert!(calendar.contains("DTSTART;TZID=Europe/London:20250329T190000\r\n"));
+        assert!(calendar.contains("DTEND;TZID=Europe/London:20250330T160000\r\n"));
+    }
+
+    #[test]
+    fn collapse_recurring() {
+        let series: Event = serde_yaml::from_str(
+            r#"
+id: weekly
+name: Tuesday balfolk
+start: 2026-10-06T19:30:00+02:00
+end: 2026-10-06T22:00:00+02:00
+timezone: Europe/Brussels
+country: Belgium
+city: Gent
+styles: [balfolk]
+social: true
+recurrence:
+  rrule: FREQ=WEEKLY;BYDAY=TU
+  except: [2026-10-20]
+  overrides:
+    - date: 2026-11-03
+      status: cancelled
+"#,
+        )
+        .unwrap();
+        let now = Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap();
+        let instances = expand(series, RecurrenceWindow::default(), now).unwrap();
+        assert!(instances.len() > 50);
+
+        let calendar = Ics::from_events(&instances, &instances, "Test", PUBLIC_URL).to_string();
+        assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(calendar.matches("UID:weekly@folkdance.page\r\n").count(), 2);
+        assert!(calendar.contains("DTSTART;TZID=Europe/Brussels:20261006T193000\r\n"));
+        // The rule ends at the last instance in the window, which the timezone must cover.
+        assert!(calendar.contains("RRULE:FREQ=WEEKLY;BYDAY=TU;UNTIL=20270928T173000Z\r\n"));
+        assert!(calendar.contains("DTSTART:20270328T020000\r\nTZOFFSETFROM:+0100\r\n"));
+        assert!(calendar.contains("EXDATE;TZID=Europe/Brussels:20261020T193000\r\n"));
+        assert!(calendar.contains("RECURRENCE-ID;TZID=Europe/Brussels:20261103T193000\r\n"));
+        assert!(calendar.contains("SUMMARY:CANCELLED: Tuesday balfolk\r\n"));
+
+        // If only some instances match, only those are included.
+        let matching: Vec<_> = instances
+            .iter()
+            .filter(|instance| instance.status == Status::Scheduled)
+            .take(2)
+            .cloned()
+            .collect();
+        let calendar = Ics::from_events(&matching, &instances, "Test", PUBLIC_URL).to_string();
+        assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 2);
+        assert_eq!(calendar.matches("UID:weekly@folkdance.page\r\n").count(), 2);
+        assert!(!calendar.contains("RRULE"));
+        assert!(!calendar.contains("EXDATE"));
+        assert!(calendar.contains("RECURRENCE-ID;TZID=Europe/Brussels:20261006T193000\r\n"));
+        assert!(calendar.contains("RECURRENCE-ID;TZID=Europe/Brussels:20261013T193000\r\n"));
+        assert!(!calendar.contains("20261027T193000"));
+    }
+
+    #[test]
+    fn rrule_until_replaces_end() {
+        let last = EventTime::DateOnly {
+            start_date: NaiveDate::from_ymd_opt(2027, 3, 6).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2027, 3, 6).unwrap(),
+        };
+        assert_eq!(
+            rrule_until("FREQ=MONTHLY;BYDAY=1SA", &last),
+            "FREQ=MONTHLY;BYDAY=1SA;UNTIL=20270306"
+        );
+        assert_eq!(
+            rrule_until("FREQ=MONTHLY;COUNT=100;BYDAY=1SA", &last),
+            "FREQ=MONTHLY;BYDAY=1SA;UNTIL=20270306"
+        );
+        assert_eq!(
+            rrule_until("FREQ=MONTHLY;BYDAY=1SA;UNTIL=20271231T000000Z", &last),
+            "FREQ=MONTHLY;BYDAY=1SA;UNTIL=20270306"
+        );
+    }
+
+    #[test]
+    fn no_timezone_utc() {
+        let event = Event {
+            time: EventTime::DateTime {
+                start: FixedOffset::east_opt(3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2025, 3, 1, 19, 0, 0)
+                    .unwrap(),
+                end: FixedOffset::east_opt(3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2025, 3, 1, 23, 0, 0)
+                    .unwrap(),
+            },
+            ..test_event()
+        };
+        let events = [event];
+        let calendar = Ics::from_events(&events, &events, "Test", PUBLIC_URL).to_string();
+        assert!(!calendar.contains("VTIMEZONE"));
+        assert!(calendar.contains("DTSTART:20250301T180000Z\r\n"));
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(format_offset(FixedOffset::east_opt(0).unwrap()), "+0000");
+        assert_eq!(
+            format_offset(FixedOffset::east_opt(19800).unwrap()),
+            "+0530"
+        );
+        assert_eq!(
+            format_offset(FixedOffset::west_opt(12600).unwrap()),
+            "-0330"
+        );
+        assert_eq!(format_offset(FixedOffset::east_opt(61).unwrap()), "+000101");
+    }
 
     #[tokio::test]
     async fn empty() {
         let calendar = Calendar::new();
-        let ics = Ics(calendar);
+        let ics = Ics::from(calendar);
         let response = ics.into_response();
         assert_eq!(
             body::to_bytes(response.into_body(), 2000).await.unwrap(),
@@ -145,7 +955,7 @@ END:VCALENDAR\r
                     .done(),
             )
             .done();
-        let ics = Ics(calendar);
+        let ics = Ics::from(calendar);
         let response = ics.into_response();
         assert_eq!(
             body::to_bytes(response.into_body(), 2000).await.unwrap(),
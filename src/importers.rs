@@ -12,8 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub mod bands;
-pub mod callers;
 pub mod folkbalbende;
 pub mod icalendar;
 pub mod plugevents;
@@ -23,10 +21,13 @@ pub mod webfeet;
 use std::{
     collections::HashMap,
     fs::{create_dir_all, write},
-    path::{Path, PathBuf},
+    path::Path,
 };
 
-use crate::{github::to_safe_filename, model::events::Events};
+use crate::{
+    github::file_for_country,
+    model::{events::Events, performers::Performer},
+};
 use eyre::Report;
 use log::info;
 
@@ -56,11 +57,19 @@ fn combine_events(old_events: Events, new_events: Events) -> Events {
     events
 }
 
-/// Given a set of events, splits them by country then writes one file for each country.
+/// Given a set of events, splits them by country then writes one file for each country with the
+/// given name, in the directory for that country used by
+/// [`choose_file_for_event`](crate::github::choose_file_for_event).
 ///
 /// If the file already exists for that country then applies the logic from [`combine_events`] to
 /// preserve old events in it.
 pub fn write_by_country(events: Events, filename: &Path) -> Result<(), Report> {
+    write_by_country_in(Path::new(""), events, filename)
+}
+
+/// Like [`write_by_country`], but relative to the given root of the events repository rather than
+/// the current directory.
+fn write_by_country_in(root: &Path, events: Events, filename: &Path) -> Result<(), Report> {
     let mut events_by_country: HashMap<String, Events> = HashMap::new();
     for event in events.events {
         events_by_country
@@ -70,10 +79,7 @@ pub fn write_by_country(events: Events, filename: &Path) -> Result<(), Report> {
             .push(event);
     }
     for (country, mut country_events) in events_by_country {
-        let mut country_filename = PathBuf::new();
-        country_filename.push("events");
-        country_filename.push(to_safe_filename(&country));
-        country_filename.push(filename);
+        let country_filename = root.join(file_for_country(&country, &filename.to_string_lossy()));
         info!(
             "Writing {} events to {:?}",
             country_events.events.len(),
@@ -91,28 +97,27 @@ pub fn write_by_country(events: Events, filename: &Path) -> Result<(), Report> {
     Ok(())
 }
 
-/// Returns strings from the slice which are contained in one of the two lowercase strings passed.
+/// Returns the names of performers from the slice whose name or any alias is contained in one of
+/// the two lowercase strings passed.
 ///
 /// Also finds matches where "&" is replaced with "and".
-fn lowercase_matches(needles: &[&str], a: &str, b: &str) -> Vec<String> {
-    needles
+fn lowercase_matches(performers: &[Performer], a: &str, b: &str) -> Vec<String> {
+    performers
         .iter()
-        .filter_map(|needle| {
-            let needle_lower = needle.to_lowercase();
-            let needle_and = needle_lower.replace("&", "and");
-            let needle_und = needle_lower.replace("&", "und");
-            if a.contains(&needle_lower)
-                || b.contains(&needle_lower)
-                || a.contains(&needle_and)
-                || b.contains(&needle_and)
-                || a.contains(&needle_und)
-                || b.contains(&needle_und)
-            {
-                Some(needle.to_string())
-            } else {
-                None
-            }
+        .filter(|performer| {
+            performer.names().any(|needle| {
+                let needle_lower = needle.to_lowercase();
+                let needle_and = needle_lower.replace("&", "and");
+                let needle_und = needle_lower.replace("&", "und");
+                a.contains(&needle_lower)
+                    || b.contains(&needle_lower)
+                    || a.contains(&needle_and)
+                    || b.contains(&needle_and)
+                    || a.contains(&needle_und)
+                    || b.contains(&needle_und)
+            })
         })
+        .map(|performer| performer.name.clone())
         .collect()
 }
 
@@ -120,32 +125,95 @@ fn lowercase_matches(needles: &[&str], a: &str, b: &str) -> Vec<String> {
 mod tests {
     use super::*;
     use crate::model::{
+        attendance::AttendanceMode,
         dancestyle::DanceStyle,
         event::{Event, EventTime},
+        status::Status,
     };
     use chrono::NaiveDate;
 
     fn make_event(name: &str, time: EventTime) -> Event {
         Event {
+            id: None,
             name: name.to_string(),
             time,
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         }
     }
 
+    #[test]
+    fn write_online_events_per_importer() {
+        let root = std::env::temp_dir().join(format!("dancelist-{}", Event::new_id()));
+        let online_event = |name: &str, day| Event {
+            country: String::new(),
+            city: String::new(),
+            ..make_event(
+                name,
+                EventTime::DateOnly {
+                    start_date: NaiveDate::from_ymd_opt(2099, 1, day).unwrap(),
+                    end_date: NaiveDate::from_ymd_opt(2099, 1, day).unwrap(),
+                },
+            )
+        };
+
+        write_by_country_in(
+            &root,
+            Events {
+                events: vec![online_event("First", 2)],
+            },
+            Path::new("first.yaml"),
+        )
+        .unwrap();
+        write_by_country_in(
+            &root,
+            Events {
+                events: vec![online_event("Second", 1)],
+            },
+            Path::new("second.yaml"),
+        )
+        .unwrap();
+
+        // The second importer doesn't replace the upcoming events of the first.
+        let first =
+            Events::load_file_without_validation(&root.join("events/online/first.yaml")).unwrap();
+        let second =
+            Events::load_file_without_validation(&root.join("events/online/second.yaml")).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(first.events, vec![online_event("First", 2)]);
+        assert_eq!(second.events, vec![online_event("Second", 1)]);
+    }
+
     #[test]
     fn combine_no_old() {
         let old_events = Events::default();
@@ -266,19 +334,34 @@ mod tests {
 
     #[test]
     fn match_band() {
-        const TEST_BANDS: [&str; 3] = ["Matt Norman & Edward Wallace", "Nozzy", "Nubia"];
+        let performer = |name: &str, aliases: &[&str]| Performer {
+            name: name.to_string(),
+            aliases: aliases.iter().map(ToString::to_string).collect(),
+            country: None,
+            website: None,
+        };
+        let test_bands = [
+            performer("Matt Norman & Edward Wallace", &[]),
+            performer("Nozzy", &[]),
+            performer("Nubia", &["Nubia Trio"]),
+            performer("Topette!!", &["Topette"]),
+        ];
 
         assert_eq!(
-            lowercase_matches(&TEST_BANDS, "with nozzy", "and nubia"),
+            lowercase_matches(&test_bands, "with nozzy", "and nubia"),
             vec!["Nozzy".to_string(), "Nubia".to_string()]
         );
         assert_eq!(
             lowercase_matches(
-                &TEST_BANDS,
+                &test_bands,
                 "bob morgan with matt norman and edward wallace",
                 ""
             ),
             vec!["Matt Norman & Edward Wallace".to_string()]
         );
+        assert_eq!(
+            lowercase_matches(&test_bands, "bal with topette", ""),
+            vec!["Topette!!".to_string()]
+        );
     }
 }
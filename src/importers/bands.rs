@@ -1,688 +0,0 @@
-// Copyright 2024 the dancelist authors.
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-//     https://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-pub const BANDS: &[&str] = &[
-    "112 and Then Some",
-    "A Cat's Tale",
-    "A Fine Kettle of Fish",
-    "A Joyful Noise",
-    "Abacus",
-    "Accentricity",
-    "Accords Perdus",
-    "Achterband",
-    "Adam Rich-Griffin & Nicola McConkey",
-    "AdHoc Orkest",
-    "Adriano Sangineto",
-    "Aérokorda",
-    "Afton Crossing",
-    "Air de Famille",
-    "Airboxes",
-    "Akleja",
-    "Alchemy",
-    "All Comers Band",
-    "All Keyed Up",
-    "Allemans Katt",
-    "AlleMonOh Stringband",
-    "Amarillis & Anders",
-    "Amberwing",
-    "Anadama",
-    "Andawenn",
-    "Andrea Capezzuoli",
-    "Andrea Hoag",
-    "Andreas Neumann",
-    "Androneda",
-    "Andy Cutting",
-    "Angelika Hudler & Laurenz Schiffermüller",
-    "Antanjo",
-    "Anytime English Players",
-    "Appalachian Roots",
-    "Arcotones",
-    "Artisjok",
-    "Atlantic Crossing",
-    "Att Dansa",
-    "Aubergine",
-    "Aurélien Claranbaux",
-    "Autour de Bâle",
-    "Avalance",
-    "Avourneen",
-    "ba.fnu",
-    "Baby Corns",
-    "Back Row Band",
-    "Baftig Collectief",
-    "Bal à Sept",
-    "Bal Affaire",
-    "Bal O'Gadjo",
-    "Balbuzar",
-    "Ball Noir",
-    "Ballkanesen",
-    "Ballo Allegro",
-    "Bamako Express",
-    "Bandage a Trois",
-    "Banna Damhsa",
-    "Bare Necessities",
-    "Bargainatt",
-    "Barock op de Deel",
-    "Bart Praet",
-    "Bascule",
-    "Bearded Dragons",
-    "Beat Bouet Trio",
-    "Bellamira",
-    "Ben Bolker & Susanne Maziarz",
-    "Benjamin Rowe & Adam Rich-Griffin",
-    "Berkenwerk",
-    "Big Brandy",
-    "Big Celtic Fun",
-    "Big Fun",
-    "Biskaya",
-    "Black Cat Quadrille",
-    "Blind Squirrel",
-    "Bluejay",
-    "BmB",
-    "Boccadoro",
-    "Bodarc",
-    "Bokpojkarna",
-    "Bone Shakers",
-    "Bosnick & Bittel",
-    "Bougnat Sound",
-    "Bourrée Party Crackers",
-    "Bouton",
-    "Bowrider",
-    "Box & String",
-    "Box and String Trio",
-    "Box of Frogs",
-    "Boxwood",
-    "Brasstown Balfolk Band",
-    "Brazenkeys",
-    "Brickyard Joes",
-    "Broes",
-    "Brook Farm String Band",
-    "Brooklyn Swing Ensemble",
-    "Budweis-Wunderlich",
-    "Bunny Bread Bandits",
-    "Calico",
-    "Calliope",
-    "Cambia",
-    "Cardboard Cabin",
-    "Carin Greve",
-    "Carousel Band",
-    "Cecilia",
-    "Celtic Connection",
-    "Celtic Constellation",
-    "Chablis",
-    "Chaotic Good",
-    "Chardon",
-    "Cheap Shots",
-    "Chicken Feathers",
-    "Chimney Swift",
-    "Chrástu",
-    "Chris Liakos & John Wetzel",
-    "Ciac Boum",
-    "Ciona",
-    "CLARABAL",
-    "Cloudburst",
-    "Cock and Bull Band",
-    "Cojiro",
-    "Confluence",
-    "Constellation",
-    "Contra Banditos",
-    "Contra Fusion",
-    "Contra Intuitive",
-    "Contra Quartet",
-    "Contra Sutra",
-    "ContraForce",
-    "Contrary Faeries",
-    "Contrasaurus",
-    "Contratopia",
-    "Contraverts",
-    "Coral Riff",
-    "Cosmic Otters",
-    "Countercurrent",
-    "Crossover Duo",
-    "Crossover Trio",
-    "Cutie and the Hot Wings",
-    "Danse Café",
-    "Danzvogel",
-    "David Cornelissen",
-    "David James & Craig Johnson",
-    "De Houtzagerij",
-    "De Trekvogels",
-    "Dead Sea Squirrels",
-    "Definitely Contraband",
-    "Deux Sans Frontières",
-    "Devilish Mary",
-    "Die Dudelsacksen",
-    "Die Hayner",
-    "Die ZWEI",
-    "DJ TacoShel",
-    "Djîga",
-    "Dobunni",
-    "Dodging Pheasants",
-    "Dogtown",
-    "Dos en Trío",
-    "Doxatunes",
-    "Dragon Fire",
-    "Dreamy Folk Flow",
-    "Drehwurm",
-    "Drive Train",
-    "Drøn",
-    "Duet Friends",
-    "Duo Absynthe",
-    "Duo Astrel",
-    "Duo Baftig",
-    "Duo Bottasso",
-    "Duo Brotto Milleret",
-    "Duo Cardamon",
-    "Duo Clercx",
-    "Duo Coudroy Genet",
-    "Duo De Schepper-Sanczuk",
-    "Duo du Balcon",
-    "Duo FA",
-    "Duo Gielen-Buscan",
-    "Duo Kouran Der",
-    "Duo l'Hêtre Heureux",
-    "Duo Mackie/Hendrix",
-    "Duo Mistral",
-    "Duo Montanaro-Cavez",
-    "Duo Nello",
-    "Duo Noordwest",
-    "Duo Obsessie",
-    "Duo Pacher-Roblin",
-    "Duo Roblin-Thébaut",
-    "Duo Super Nova",
-    "Duo Thézé",
-    "Duo Torv",
-    "Duo Vandenabeele-Knapen",
-    "Duo Wolff-Moschcau",
-    "DuOblique",
-    "DURRbanda",
-    "Edentia",
-    "Eins68",
-    "Elba Connection",
-    "Elegant Echoes",
-    "Elise, Mira & Pat",
-    "Elixir",
-    "Eloise & Co.",
-    "EmBRUN",
-    "Emelie Waldken",
-    "Emily & The Simons",
-    "Emma & Jonas",
-    "Engine Room",
-    "English Contra Dance Band",
-    "Erik en Martijn",
-    "Euphor",
-    "Ewout & Anouk",
-    "Exqueezit",
-    "Fahrenheit",
-    "Fat Fiddles",
-    "Feather and Fox",
-    "Feephifolk",
-    "Festnoz42",
-    "Fiddle Hellions Big Band",
-    "Fiddle Hellions",
-    "Fiddlechicks",
-    "Filippo Gambetta",
-    "Fior",
-    "Firecrest",
-    "Firletanz",
-    "First Time Stringband",
-    "Fjellerkene",
-    "Fjodor de Harpist",
-    "Flatland Consort",
-    "Floating Sofa Quartet",
-    "Floris & the Flames",
-    "Flos",
-    "Flour City Doughboys",
-    "Flying Cat",
-    "Flying Romanos",
-    "Folie du Nord",
-    "Folk Knock",
-    "Folk Voice Band",
-    "Folkinger",
-    "Folkschwestern Biese",
-    "Fourpence",
-    "Free Association",
-    "French Toast",
-    "Friday Folk Band",
-    "Friends of Virgil",
-    "Froghammer",
-    "Frosty Frog",
-    "Full Moon Country Dance Orchestra",
-    "Full Tilt",
-    "Fyndus",
-    "Fyr Freya",
-    "Gaelstorm",
-    "Gammeldansk",
-    "George Paul",
-    "Geronimo",
-    "GIG CB!",
-    "Ginger Jam",
-    "Ginkgo",
-    "Gisbert",
-    "Go Figure",
-    "Golders Green",
-    "Good Company",
-    "Good Intentions",
-    "Good Time Old Time",
-    "Gott Folk!",
-    "Göze",
-    "Grace Haaland",
-    "Grand Balthazar",
-    "Grand Picnic",
-    "Gray & Friends",
-    "GrayScale",
-    "Green Willow",
-    "Greg & Aiden Boardman",
-    "Gribouille",
-    "Griff Trio",
-    "Groove Factory",
-    "Guillaume Sparrow-Pepin & Rachel Bell",
-    "Gwendolyn",
-    "Hale Bill & the Bopps",
-    "Harken!",
-    "Hartwin Dhoore Trio",
-    "Hartwin Dhoore",
-    "Havok",
-    "Headwaters",
-    "Helen Gentile & Lewis Wood",
-    "Here on the Hill",
-    "High Drive",
-    "High Strung",
-    "Hijinks",
-    "Hjarta Herring",
-    "Hoggetowne Fancy",
-    "Hoik",
-    "Holiday Ball Orchestra",
-    "Hoosier Recruits",
-    "Hora Fani",
-    "Hot Coffee Breakdown",
-    "Hot Griselda",
-    "Hot Toddy",
-    "Hypergolic",
-    "I Pizzicati",
-    "Ice Cream Truckers",
-    "Idlewild",
-    "Irish Diplomacy",
-    "Javallon",
-    "Jeroen Laureyssens",
-    "Jet Bridge",
-    "Joachim Montbord",
-    "Joanna Hyde & Tadhg O Meachair",
-    "Jonathan de Neck",
-    "Jormsons Kapell",
-    "Joy Factor",
-    "Joyance",
-    "Joyride",
-    "Jumping Sharks",
-    "Jumpstart",
-    "Kabál",
-    "Kaleidoscope",
-    "Kaléidoscope",
-    "Kaw Creek",
-    "Kelten zonder Grenzen",
-    "Kikker & Findus",
-    "KinFyre",
-    "Kingfisher",
-    "Klintetten",
-    "Kördeböf",
-    "Koriander",
-    "KV Express",
-    "Kvinnene",
-    "L'air Inconnu",
-    "L’equipe",
-    "La Belle Ivresse",
-    "La Bonne Soupe",
-    "La Machine",
-    "La Mézanj",
-    "La Réveilleuse",
-    "La Sauterelle",
-    "LAB 160",
-    "LAB162",
-    "Lackawanna Longnecks",
-    "Lagomorph Trio",
-    "Lake Effect",
-    "Laouen",
-    "Larks in the Attic",
-    "Laurie Fisher & Nik Coker",
-    "Le Bal Taquin",
-    "Le Carillon",
-    "Le Driadi",
-    "Le Mange Bal",
-    "Leben & Schwarz",
-    "Les Bottines Artistiques",
-    "Les Kickeuses",
-    "Les Quatre Patates",
-    "Les QuetscheKaschde",
-    "Les Soeurettes",
-    "Les Zéoles",
-    "Leveret",
-    "Liberty String Band",
-    "Light Migrations",
-    "Lizzy's Cocktail",
-    "Local On-Call Orchestra",
-    "Lone Star Pirates",
-    "Long Forgotten String Band",
-    "Loose Shoes",
-    "Lovely Lane String Band",
-    "Luca Fiorini",
-    "Lys&Co",
-    "Mad Robins Contra Collective",
-    "Madlot",
-    "Magistal",
-    "MaJoPlus",
-    "MANdolinMAN",
-    "Manesse Hopp",
-    "Mara Menzel",
-    "Maracu",
-    "Marbelous Daves",
-    "Marfinara",
-    "Marie Paulette",
-    "Martina & Gisbert & Rainer",
-    "Matt Norman & Edward Wallace",
-    "Matt Norman & Hannah Cummings",
-    "Matt Norman",
-    "Mavro Meli",
-    "Meadowhawk",
-    "Merriment",
-    "MetroGnomes",
-    "Mevilish Merry",
-    "Mickey Koth & John Kalinowski",
-    "Midnight on the Water",
-    "Mieneke",
-    "Millserati",
-    "Minimum Syndical",
-    "Miriquidi",
-    "Mo Brachfeld & Friends",
-    "Moab Community Dance Band",
-    "Mockingbird",
-    "Momiro",
-    "Monday Night All Stars",
-    "Mook",
-    "Moore & Moss",
-    "Morceau de Breizh",
-    "Morrison Brothers",
-    "Moss & Smith",
-    "Mound City Slickers",
-    "Mountain Laurels",
-    "Mr Folxlide",
-    "Musac",
-    "Mystic Gorillas",
-    "Nachtmuziek",
-    "Naragonia Quartet",
-    "Naragonia",
-    "Naredi",
-    "Nebel",
-    "Neon Creek",
-    "New Hoosier Broadcasters",
-    "New Reliables",
-    "Night Heron",
-    "No Bad Ideas",
-    "Noiranomis",
-    "NoLa",
-    "Nordic Exposure",
-    "Nordic Spirit",
-    "Norrsken",
-    "North Dixie Boom Chicks",
-    "Northern Aire",
-    "Northwoods",
-    "Notorious",
-    "Nova",
-    "Nozzy",
-    "Nubia",
-    "Nut en Genoegen",
-    "Odevare",
-    "Oh CONTRAire",
-    "Old Time All Stars",
-    "Olivier Valence",
-    "OMGDude",
-    "Oraj",
-    "Ormuz",
-    "Pamplemousse",
-    "Panjandrum",
-    "Pantonim",
-    "Paracetamol",
-    "Paradocs",
-    "Parasol",
-    "Penny Farthing",
-    "Persons of Quality",
-    "Peter and the Wolf Tones",
-    "Peter Michealsen & Joe Finn",
-    "PFM!",
-    "Philu",
-    "Pimento Mori",
-    "Pine Tree Flyers",
-    "Plan B",
-    "Playing with Fyre",
-    "Plönk",
-    "Pont Ondulé",
-    "Portland Megaband",
-    "Portmanteau",
-    "Quicksilver",
-    "QuiVive",
-    "Ragged Robin",
-    "Ratatouille",
-    "Raven & Goose",
-    "Red Case Band",
-    "Red Dog Riley",
-    "Red River Band",
-    "Reelplay",
-    "Rémi Geffroy",
-    "Rest 'n' Peas",
-    "Reverie",
-    "Rhys Jones and the Imaginary Beings",
-    "Rhythmajigs",
-    "River Music",
-    "River Road",
-    "Roguery",
-    "Rokkende Vrouwen",
-    "Root System",
-    "Rosenthorns",
-    "Round Hill Ramblers",
-    "Rum & Onions",
-    "Sail Away Ladies",
-    "ScenicRoots",
-    "Scrod Pudding",
-    "Seattle Lilla Spelmanslag",
-    "Seattle Skandia Spelmanslag",
-    "Senior Moment",
-    "Serendipity",
-    "Sessie Rotterdam",
-    "Shandy",
-    "Shillelagh",
-    "Sigmar Gothe",
-    "Simone Bottasso",
-    "Sister Haggis",
-    "Six Feet Back",
-    "Skandia Kapell",
-    "Skavidra",
-    "Smith, Campeau & Nelson",
-    "Snaarmaarwaar",
-    "Snapdragon",
-    "Snappin' Bug Stringband",
-    "Snorty Horse",
-    "Soldo",
-    "Solid Ghost",
-    "Sølvstål | Silversteel",
-    "Sonoma Crossing",
-    "Southwind",
-    "Sparv",
-    "Spintuition",
-    "Sprida Ut",
-    "Spring Chickens",
-    "Spring Greens",
-    "SpringTide",
-    "Stampij",
-    "Starling",
-    "StatiK",
-    "Steam Machine",
-    "Steam Packet",
-    "Steam!",
-    "Stefano Baldan",
-    "Steppin' Stones",
-    "Stepwise",
-    "Stomp Rocket",
-    "Stomping Ground",
-    "Stove Dragon",
-    "Stringrays",
-    "Strings on Wings",
-    "Stromlosplus",
-    "Sture und Sigmar",
-    "Sumagist",
-    "Sunbird",
-    "Supertrad",
-    "Swartematerie",
-    "Swinco",
-    "Swingology",
-    "Sycamore",
-    "Tabbies Delight",
-    "Take a Dance",
-    "Taranteira",
-    "Tea & Whiskey",
-    "Tea for Three",
-    "Tetra",
-    "Thalas",
-    "The 32 Bartenders",
-    "The Atchisons",
-    "The Bismarcks",
-    "The Black Cat Quadrille",
-    "The Boom Chicks",
-    "The Brickersons",
-    "The Bubblegum Society",
-    "The Campeau Creek Boys",
-    "The Cat Wranglers",
-    "The Chivalrous Crickets",
-    "The Contra Rebels",
-    "The Dam Beavers",
-    "The English Muffins",
-    "The Euphemists",
-    "The Evil Twins",
-    "The Faux Paws",
-    "The Fiddling Thomsons",
-    "The Figments",
-    "The Flying Elbows",
-    "The Free Raisins",
-    "The French Connection",
-    "The Gaslight Tinkers",
-    "The Guys",
-    "The Hollertones",
-    "The Jig Lords",
-    "The Johns",
-    "The Land Band",
-    "The Latter Day Lizards",
-    "The Little Big Band",
-    "The Lost Keys",
-    "The McKenzies",
-    "The Moving Violations",
-    "The Orphans",
-    "The Outsiders",
-    "The Pegasus Collective",
-    "The Rafter Ringers",
-    "The Rhythm Raptors",
-    "The Rythmajigs",
-    "The Star Charters",
-    "The String Bean Serenaders",
-    "The Syncopaths",
-    "The Tater Bugs",
-    "The Third Degree",
-    "The Turning Stile",
-    "The Underdogs",
-    "The Usual Suspects",
-    "Thorn Among the Roses",
-    "Three Bits",
-    "Threo",
-    "Thunderwing",
-    "TinnFelen",
-    "Toad Holler",
-    "Toad in the Hole",
-    "Toil and Treble",
-    "Tom & Aurélie",
-    "Toot, Squeeze & Co.",
-    "Tooth Pickers",
-    "Topette",
-    "Toppish",
-    "Topspin",
-    "Torrent",
-    "Toss the Possum",
-    "TradTöchter",
-    "Tralala Lovers",
-    "Treble Play",
-    "Tref",
-    "Triaz",
-    "Tribal Jâze",
-    "Trillium",
-    "Trio Baftig",
-    "Trio Con Brio",
-    "Trio DMR",
-    "Trio Loubelya",
-    "Trio Mirambel",
-    "Trio Toxico",
-    "TriOblique",
-    "Trip Hazard",
-    "Trip to Norwich",
-    "Triple-X",
-    "Triptych",
-    "Trouveur Valdoten",
-    "Tunebuggy",
-    "Turning Stile",
-    "Turnip the Beet",
-    "TWeLve",
-    "Two Catch a Raindrop",
-    "Two Hats",
-    "Ultra Tagada",
-    "Unbowed",
-    "Unipot",
-    "Unstrung String Band",
-    "Uppsala",
-    "Ute+Michael",
-    "Vagalan",
-    "Valentin Barray",
-    "Vandiekomsa",
-    "Venn",
-    "Viadeyra",
-    "Viola Voilá",
-    "Vionelles",
-    "Viorel",
-    "Virginia Creepers",
-    "Vlier",
-    "Wabi Sabi",
-    "Wakarusa Roundabouts",
-    "Wake Up Robin",
-    "Warleggan Village Band",
-    "Warmoes",
-    "Waterbound String Band",
-    "Waverley Station",
-    "Wee Merry Banshees",
-    "Weirdstrings",
-    "Wergleyberg",
-    "Westminster Society Players",
-    "Westside Warblers",
-    "Wheels of the World",
-    "Whirled Peas",
-    "Wild Asparagus",
-    "Wild Notes",
-    "Wild Thistle",
-    "Wild Wombats of the Chesapeake",
-    "WillPower",
-    "Wilma",
-    "Wim te Groen",
-    "Windsor Knot",
-    "Wings & Tales",
-    "Wissahickon Express",
-    "Wouter en de Draak",
-    "Wouter Kuyper",
-    "Wry Bred",
-    "Xenia Twarz",
-    "YanYana",
-    "Yanyk",
-    "Zéphyr Combo",
-];
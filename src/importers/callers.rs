@@ -1,352 +0,0 @@
-// Copyright 2024 the dancelist authors.
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-//     https://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-
-pub const CALLERS: &[&str] = &[
-    "Abigail Golden",
-    "Abigail Hobart",
-    "Ada Diane Blanken",
-    "Adam Hughes",
-    "Adina Gordon",
-    "Alan Rosenthal",
-    "Alan Winston",
-    "Alex Deis-Lauby",
-    "Alice Raibourn",
-    "Alice Smith-Goeke",
-    "Alyssa Adkins",
-    "Amy Letson",
-    "Andrea Nettleton",
-    "Andrew Shaw",
-    "Andrew Stout",
-    "Andrew Swaine",
-    "Andy King",
-    "Angela DeCarlis",
-    "Ann Carter",
-    "Ann Fallon",
-    "Anna Claire Camp",
-    "Anna Rain",
-    "Annie Fain Barralon",
-    "Annie Kidwell",
-    "April Blum",
-    "Barbara Finney",
-    "Barrett Grimm",
-    "Beau Farmer",
-    "Ben Allbrandt",
-    "Ben Sachs-Hamilton",
-    "Ben Sela",
-    "Beth Mast",
-    "Beth Molaro",
-    "Bev Bernbaum",
-    "Beverly Francis",
-    "Bill Olson",
-    "Billy Fischer",
-    "Bob Barrett",
-    "Bob Devaty",
-    "Bob Fabinski",
-    "Bob Frederking",
-    "Bob Green",
-    "Bob Isaacs",
-    "Bob Messer",
-    "Bob Morgan",
-    "Bob Nicholson",
-    "Brad Foster",
-    "Brenda Sledgianowski",
-    "Brian Hamshar",
-    "Bridget Whitehead",
-    "Bronwyn Chelette",
-    "Brooke Friendly",
-    "Bruce Hamilton",
-    "Cara King",
-    "Carl Friedman",
-    "Carl Levine",
-    "Carmen Giunta",
-    "Carol Kopp",
-    "Carol Ormand",
-    "Caroline Barnes",
-    "Carrie Dayton-Madsen",
-    "Carrie Dayton-Madsen",
-    "Cathy Campbell",
-    "Cathy Hollister",
-    "Charley Harvey",
-    "Charlie Turner",
-    "Charlotte Crittenden",
-    "Charlotte Rich-Griffin",
-    "Charmaine Slaven",
-    "Chet Gray",
-    "Chloe Levine",
-    "Chris Hernandez",
-    "Chris Page",
-    "Chrissy Fowler",
-    "Christa Torrens",
-    "Christine Merryman",
-    "Cindy Harris",
-    "Cis Hinkle",
-    "Claire Takemori",
-    "Colette Mrozek",
-    "Colin Hume",
-    "Courtney Cartwright",
-    "Craig Wolfe",
-    "Daisy Black",
-    "Dan Blim",
-    "Dan Kappus",
-    "Dan Seppeler",
-    "Daniel Popowich",
-    "Darlene Hamilton",
-    "Darlene Underwood",
-    "Dave Bateman",
-    "Dave Berman",
-    "Dave Kerridge",
-    "Dave Rupp",
-    "Dave Smukler",
-    "David Ashworth",
-    "David Eisenstadter",
-    "David Kirchner",
-    "David Macemon",
-    "David Millstone",
-    "David Newitt",
-    "David Smukler",
-    "Deanna Palumbo",
-    "Dereck Kalish",
-    "Devin Pohly",
-    "Diane Silver",
-    "Dianne Palmer-Quay",
-    "Dilip Sequeira",
-    "Don Heinold",
-    "Don Stratton",
-    "Don Veino",
-    "Donna Hunt",
-    "Dorothy Cummings",
-    "Drew Delaware",
-    "Drew Thomas",
-    "Dudley Laufman",
-    "Dugan Murphy",
-    "Earl McGill",
-    "Ed Wilkinson",
-    "Elijah Jaime",
-    "Elizabeth Estep",
-    "Ellen Hodgkin",
-    "Emily Addison",
-    "Emily Aubrey",
-    "Emily Faulkner",
-    "Emily Rush",
-    "Emma Rushton",
-    "Erik Erhardt",
-    "Erik Hoffman",
-    "Fiona Gillespie",
-    "Frank Buschelmann",
-    "Frannie Marr",
-    "Gaye Fifer",
-    "Gene Murrow",
-    "George Marshall",
-    "George Segebade",
-    "George Thompson",
-    "Gordon Potts",
-    "Graham Christian",
-    "Greer Hannan",
-    "Greg Frock",
-    "Gretchen Caldwell",
-    "Harris Lapiroff",
-    "Heather McAslan",
-    "Hila Lenz",
-    "Imogen Mills",
-    "Jack Kanutin",
-    "Jacob LeGrone",
-    "Jacqui Grennan",
-    "Jake Turin",
-    "Jake Wood",
-    "James Hutson",
-    "Jane Bird",
-    "Jane Usher",
-    "Janet Shepherd",
-    "Janine Smith",
-    "Jay Rauch",
-    "Jen Jasenski",
-    "Jen Morgan",
-    "Jenna Simpson",
-    "Jennifer Staples",
-    "Jenny Beer",
-    "Jenny Fraser",
-    "Jeremy Child",
-    "Jeremy Korr",
-    "Jesse Partridge",
-    "Jill Allen",
-    "Jill Delaney",
-    "Jim Hemphill",
-    "Jim Thaxter",
-    "JJ Luxe",
-    "Joanna Reiner Wilkinson",
-    "Joe DePaolo",
-    "Joe Harrington",
-    "Joe Wilkie",
-    "John Krumm",
-    "John Notgrass",
-    "Jordan Kammeyer",
-    "Joseph Pimentel",
-    "Joy Greenwolfe",
-    "Judi Rivkin",
-    "Julian Blechner",
-    "Julie Esch",
-    "Julie Whatmough",
-    "Juliette Webb",
-    "Kalia Kliban",
-    "Kappy Laning",
-    "Karen Andrews",
-    "Karen Jackson",
-    "Karen Justin",
-    "Kate Nealley",
-    "Katie Zanders",
-    "Katy Heine",
-    "Kelly Tabor",
-    "Kelsey Hartman",
-    "Ken Gall",
-    "Kenny Greer",
-    "Kim Forry",
-    "Kim Thompson",
-    "Koren Wake",
-    "Kris Rosar",
-    "Laura Beraha",
-    "Laura Hudlow",
-    "Laurel Thomas",
-    "Lauren Catlin",
-    "Lauren Wilson",
-    "Laurie Pietravalle",
-    "Lewis Land",
-    "Liam Binley",
-    "Liesl Jandrey",
-    "Linda Cooper",
-    "Linda Nelson",
-    "Lindsay Verbil",
-    "Lindsey Dono",
-    "Lisa Greenleaf",
-    "Lisa Harris-Frydman",
-    "Lisa Heywood",
-    "Lisa Newcomb",
-    "Lise Dyckman",
-    "Liz Burkhart",
-    "Liz Nelson",
-    "Louise Siddons",
-    "Luke Donforth",
-    "Luke Schoppert",
-    "Lydia Molineaux",
-    "Mae Wilson",
-    "Maeve Devlin",
-    "Maggie McRae",
-    "Maia McCormick",
-    "Malcolm Jowett",
-    "Marc Airhart",
-    "Margaret Bary",
-    "Margaret Goodman",
-    "Mark Elvins",
-    "Marlin Whitaker",
-    "Marshall Bradshaw",
-    "Martha Kent",
-    "Mary Luckhardt",
-    "Mary Wesley",
-    "Melissa Chatham",
-    "Michael Catovsky",
-    "Michael Karcher",
-    "Mike Bennett",
-    "Mike Ruff",
-    "Myra Hirschberg",
-    "Nicholas Smith",
-    "Nicola Scott",
-    "Nils Fredland",
-    "Noah Grunzweig",
-    "Olivia Barry",
-    "Orly Krasner",
-    "Owen Hale",
-    "Paul Garner",
-    "Paul Ross",
-    "Paul Wilde",
-    "Penelope Weinberger",
-    "Peter Stix",
-    "Peter Wollenberg",
-    "Quena Crain",
-    "Qwill Duvall",
-    "Rab Cummings",
-    "Rachel Ameen",
-    "Rachel Pusey",
-    "Rebecca Anger",
-    "Renée Camus",
-    "Rhi Davies",
-    "Rhodri Davies",
-    "Ric Goldman",
-    "Rich Goss",
-    "Rich MacMath",
-    "Rich Sbardella",
-    "Richard Ades",
-    "Rick Mohr",
-    "Rick Szumski",
-    "River Abel",
-    "River Rainbowface",
-    "Rob Humphrey",
-    "Ron Buchanan",
-    "Ron Young",
-    "Sally Vernon",
-    "Sam Rotenberg",
-    "Sam Smith",
-    "Sam Tetley Smith",
-    "Sandy Lafleur",
-    "Sarah Kaiser",
-    "Sarah Trop",
-    "Scott Higgs",
-    "Seth Tepfer",
-    "Sharon Green",
-    "Sharon McKinley",
-    "Sheena Masson",
-    "Steph West",
-    "Stephanie Marie",
-    "Steve Gester",
-    "Steve Holland",
-    "Steve Otlowski",
-    "Steve Pike",
-    "Steve White",
-    "Steve Zakon-Anderson",
-    "Sue Gola",
-    "Sue Rosen",
-    "Sunny Lawrence",
-    "Susan English",
-    "Susan Kevra",
-    "Susan Michaels",
-    "Susan Petrick",
-    "Susan Taylor",
-    "Susie Kendig",
-    "Suzanne Farmer",
-    "Tamara Loewenthal",
-    "Tanya Rotenberg",
-    "Tara Bolker",
-    "Ted Crane",
-    "Ted Hodapp",
-    "Ted Rudofker",
-    "Terry Doyle",
-    "Thea Nettleton",
-    "Tiffany Okerman",
-    "Timothy Klein",
-    "Tod Whittemore",
-    "Tom Callwell",
-    "Tom Greene",
-    "Tom Mack",
-    "Tom Roby",
-    "Tom Spilsbury",
-    "Val Medve",
-    "Valerie Helbert",
-    "Vicki Morrison",
-    "Victor Gascon",
-    "Walter Zagorski",
-    "Warren Doyle",
-    "Wendy Graham",
-    "Wendy Harrup",
-    "Will Mentor",
-    "William Watson",
-    "Zach Kaplan",
-];
@@ -17,9 +17,12 @@ mod types;
 use self::types::{Event, EventType};
 use crate::{
     model::{
+        attendance::AttendanceMode,
         dancestyle::DanceStyle,
         event::{self, EventTime},
         events::Events,
+        price::Price,
+        status::Status,
     },
     util::local_datetime_to_fixed_offset,
 };
@@ -144,11 +147,9 @@ fn convert(event: &Event) -> Vec<event::Event> {
         let max_price = prices.iter().max();
         if let (Some(min_price), Some(max_price)) = (min_price, max_price) {
             Some(if *min_price == -1 {
-                "donation".to_string()
-            } else if min_price == max_price {
-                format!("€{min_price}")
+                Price::donation()
             } else {
-                format!("€{min_price}-€{max_price}")
+                Price::range((*min_price).into(), (*max_price).into(), "EUR")
             })
         } else {
             None
@@ -263,21 +264,44 @@ fn convert(event: &Event) -> Vec<event::Event> {
     dates
         .iter()
         .map(|&date| event::Event {
+            id: None,
             name: name.clone(),
             details: Some(details.clone()),
             links: links.clone(),
             time: make_time(date, start_time, end_time),
+            timezone: None,
             country: "Belgium".to_string(),
             state: None,
             city: city.to_owned(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::Balfolk],
             workshop,
             social,
             bands: bands.clone(),
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: price.clone(),
             organisation: organisation.clone(),
-            cancelled: event.cancelled,
+            status: if event.cancelled {
+                Status::Cancelled
+            } else {
+                Status::Scheduled
+            },
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         })
         .collect()
@@ -32,12 +32,18 @@ pub mod skandia;
 pub mod spreefolk;
 pub mod stroud;
 
-use super::{bands::BANDS, callers::CALLERS, combine_events, lowercase_matches};
+use super::{combine_events, lowercase_matches};
 use crate::{
+    markdown,
     model::{
+        attendance::AttendanceMode,
         dancestyle::DanceStyle,
         event::{self, EventTime},
         events::Events,
+        performers::Bands,
+        price::Price,
+        registries::Registries,
+        status::Status,
     },
     util::to_fixed_offset,
 };
@@ -71,9 +77,23 @@ trait IcalendarSource {
     /// Returns which dance styles the event includes.
     fn styles(parts: &EventParts) -> Vec<DanceStyle>;
 
+    /// Returns whether the event takes place in person, online or both.
+    fn attendance(_parts: &EventParts) -> AttendanceMode {
+        AttendanceMode::InPerson
+    }
+
     /// Converts location parts to (country, state, city).
     fn location(parts: &EventParts) -> Result<Option<(String, Option<String>, String)>, Report>;
 
+    /// Returns the names of the known bands playing at the event.
+    fn bands(parts: &EventParts, bands: &Bands) -> Vec<String> {
+        lowercase_matches(
+            &bands.bands,
+            &parts.description.to_lowercase(),
+            &parts.summary.to_lowercase(),
+        )
+    }
+
     /// Returns links for the event.
     fn links(parts: &EventParts) -> Vec<String> {
         parts.url.clone().into_iter().collect()
@@ -88,7 +108,10 @@ trait IcalendarSource {
     }
 }
 
-fn convert<S: IcalendarSource>(parts: EventParts) -> Result<Option<event::Event>, Report> {
+fn convert<S: IcalendarSource>(
+    parts: EventParts,
+    registries: &Registries,
+) -> Result<Option<event::Event>, Report> {
     let styles = S::styles(&parts);
     if styles.is_empty() {
         return Ok(None);
@@ -96,9 +119,14 @@ fn convert<S: IcalendarSource>(parts: EventParts) -> Result<Option<event::Event>
 
     let workshop = S::workshop(&parts);
     let social = S::social(&parts);
-    let Some((country, state, city)) =
+    let attendance = S::attendance(&parts);
+    let location = if attendance == AttendanceMode::Online {
+        // Online-only events don't need a physical location.
+        Some((String::new(), None, String::new()))
+    } else {
         S::location(&parts).wrap_err_with(|| format!("For event {parts:?}"))?
-    else {
+    };
+    let Some((country, state, city)) = location else {
         error!(
             "Invalid location {:?} for {:?} '{}'",
             parts.location_parts, parts.url, parts.summary
@@ -107,12 +135,16 @@ fn convert<S: IcalendarSource>(parts: EventParts) -> Result<Option<event::Event>
     };
     let links = S::links(&parts);
     let price = get_price(&parts.description)?.or(get_price(&parts.summary)?);
+    let bands = S::bands(&parts, &registries.bands);
     let description_lower = parts.description.to_lowercase();
     let summary_lower = parts.summary.to_lowercase();
-    let bands = lowercase_matches(BANDS, &description_lower, &summary_lower);
-    let callers = lowercase_matches(CALLERS, &description_lower, &summary_lower);
+    let callers = lowercase_matches(
+        &registries.callers.callers,
+        &description_lower,
+        &summary_lower,
+    );
 
-    let details = parts.description.trim().to_owned();
+    let details = markdown::from_html(parts.description.trim());
     let details = if details.is_empty() {
         None
     } else {
@@ -126,34 +158,53 @@ fn convert<S: IcalendarSource>(parts: EventParts) -> Result<Option<event::Event>
     );
 
     Ok(S::fixup(event::Event {
+        id: None,
         name: parts.summary.trim().to_owned(),
         details,
         links,
         time: parts.time,
+        timezone: None,
         country,
         state,
         city,
+        venue_id: None,
+        venue: None,
+        address: None,
+        latitude: None,
+        longitude: None,
+        attendance,
+        stream_url: None,
         styles,
         workshop,
         social,
         bands,
         callers,
+        teachers: vec![],
+        sessions: vec![],
+        level: None,
+        role_terms: None,
+        no_partner_needed: false,
+        family_friendly: false,
+        accessibility: vec![],
         price,
         organisation,
-        cancelled: false,
+        status: Status::Scheduled,
+        rescheduled_to: None,
+        recurrence: None,
+        series: None,
         source: None,
     }))
 }
 
 /// Figure out price from description.
-fn get_price(description: &str) -> Result<Option<String>, Report> {
+fn get_price(description: &str) -> Result<Option<Price>, Report> {
     let price_regexes = [
-        ("$", Regex::new(r"\$([0-9]+)").unwrap()),
-        ("£", Regex::new(r"£([0-9]+)").unwrap()),
-        ("€", Regex::new(r"€([0-9]+)").unwrap()),
-        ("€", Regex::new(r"€ ([0-9]+)").unwrap()),
-        ("€", Regex::new(r"([0-9]+) €").unwrap()),
-        ("€", Regex::new(r"([0-9]+) Euro").unwrap()),
+        ("USD", Regex::new(r"\$([0-9]+)").unwrap()),
+        ("GBP", Regex::new(r"£([0-9]+)").unwrap()),
+        ("EUR", Regex::new(r"€([0-9]+)").unwrap()),
+        ("EUR", Regex::new(r"€ ([0-9]+)").unwrap()),
+        ("EUR", Regex::new(r"([0-9]+) €").unwrap()),
+        ("EUR", Regex::new(r"([0-9]+) Euro").unwrap()),
     ];
     for (currency, regex) in price_regexes {
         let mut min_price = u32::MAX;
@@ -168,12 +219,12 @@ fn get_price(description: &str) -> Result<Option<String>, Report> {
             min_price = min(price, min_price);
             max_price = max(price, max_price);
         }
-        if min_price == u32::MAX {
-            continue;
-        } else if min_price == max_price {
-            return Ok(Some(format!("{currency}{min_price}")));
-        } else {
-            return Ok(Some(format!("{currency}{min_price}-{currency}{max_price}")));
+        if min_price != u32::MAX {
+            return Ok(Some(Price::range(
+                min_price.into(),
+                max_price.into(),
+                currency,
+            )));
         }
     }
     Ok(None)
@@ -182,13 +233,16 @@ fn get_price(description: &str) -> Result<Option<String>, Report> {
 /// Imports events from the given source, preserving the given previously imported events if
 /// appropriate.
 #[allow(private_bounds)]
-pub async fn import_events<S: IcalendarSource>(old_events: Events) -> Result<Events, Report> {
-    let new_events = import_new_events::<S>().await?;
+pub async fn import_events<S: IcalendarSource>(
+    old_events: Events,
+    registries: &Registries,
+) -> Result<Events, Report> {
+    let new_events = import_new_events::<S>(registries).await?;
     Ok(combine_events(old_events, new_events))
 }
 
 /// Fetches the iCalendar file for the given source, then converts events from it.
-async fn import_new_events<S: IcalendarSource>() -> Result<Events, Report> {
+async fn import_new_events<S: IcalendarSource>(registries: &Registries) -> Result<Events, Report> {
     let mut events = Events::default();
     for url in S::URLS {
         let calendar = S::fix_before_parse(reqwest::get(*url).await?.text().await?)
@@ -198,7 +252,7 @@ async fn import_new_events<S: IcalendarSource>() -> Result<Events, Report> {
         for uid_events in events_by_uid(&calendar).values() {
             for event in uid_events {
                 for parts in get_parts(event, timezone, uid_events)? {
-                    events.events.extend(convert::<S>(parts)?);
+                    events.events.extend(convert::<S>(parts, registries)?);
                 }
             }
         }
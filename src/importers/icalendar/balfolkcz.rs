@@ -14,8 +14,8 @@
 
 use super::{EventParts, IcalendarSource};
 use crate::{
-    importers::{bands::BANDS, lowercase_matches},
-    model::{dancestyle::DanceStyle, event::Event},
+    importers::lowercase_matches,
+    model::{dancestyle::DanceStyle, event::Event, performers::Bands},
 };
 use eyre::Report;
 
@@ -55,10 +55,12 @@ impl IcalendarSource for BalfolkCz {
         }
     }
 
+    fn bands(parts: &EventParts, bands: &Bands) -> Vec<String> {
+        // Only look at the name, as the description is bogus.
+        lowercase_matches(&bands.bands, &parts.summary.trim().to_lowercase(), "")
+    }
+
     fn fixup(mut event: Event) -> Option<Event> {
-        // Override bands from standard importer, as description is bogus.
-        let name_lower = event.name.to_lowercase();
-        event.bands = lowercase_matches(BANDS, &name_lower, "");
         event.details = None;
 
         Some(event)
@@ -178,7 +178,7 @@ impl IcalendarSource for BalfolkNl {
             if event.start_year() > 2026 {
                 return None;
             } else if event.price.is_none() {
-                event.price = Some("€5".to_string());
+                event.price = Some("€5".into());
             }
         } else if event.name.starts_with("Discoavond (") && event.city == "Amsterdam" {
             event.name = "Discoavond".to_string();
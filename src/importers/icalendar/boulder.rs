@@ -119,7 +119,7 @@ impl IcalendarSource for Boulder {
                     .links
                     .insert(0, "https://scdcolorado.org/Weekly_Classes.html".to_string());
                 if event.price.is_none() {
-                    event.price = Some("$5".to_string());
+                    event.price = Some("$5".into());
                 }
             }
             "Colorado Friends of Old Time Music and Dance,  Dance Community Contra Dance" => {
@@ -129,7 +129,7 @@ impl IcalendarSource for Boulder {
             | "Scandinavian Weekly Dance"
             | "Scandinavian Basics Class" => {
                 if event.price.is_none() {
-                    event.price = Some("$7".to_string());
+                    event.price = Some("$7".into());
                 }
             }
             _ => {}
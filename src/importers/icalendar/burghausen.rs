@@ -62,7 +62,7 @@ impl IcalendarSource for Burghausen {
                 .unwrap_or_default()
                 .contains("Unkostenbeitrag erbeten")
         {
-            event.price = Some("donation".to_string());
+            event.price = Some("donation".into());
         }
         Some(event)
     }
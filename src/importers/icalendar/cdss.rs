@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::{EventParts, IcalendarSource};
-use crate::model::{dancestyle::DanceStyle, event::Event};
+use crate::model::{attendance::AttendanceMode, dancestyle::DanceStyle, event::Event};
 use eyre::Report;
 use log::warn;
 
@@ -90,16 +90,20 @@ impl IcalendarSource for Cdss {
         !summary_lower.contains("class")
     }
 
-    fn styles(parts: &EventParts) -> Vec<DanceStyle> {
+    fn attendance(parts: &EventParts) -> AttendanceMode {
         let categories = parts.categories.as_deref().unwrap_or_default();
-        let summary_lowercase = parts.summary.to_lowercase();
-
         if categories.iter().any(|category| category == "Online Event")
-            || summary_lowercase.contains("online")
+            || parts.summary.to_lowercase().contains("online")
         {
-            // Ignore online events.
-            return vec![];
+            AttendanceMode::Online
+        } else {
+            AttendanceMode::InPerson
         }
+    }
+
+    fn styles(parts: &EventParts) -> Vec<DanceStyle> {
+        let categories = parts.categories.as_deref().unwrap_or_default();
+        let summary_lowercase = parts.summary.to_lowercase();
 
         let mut styles = Vec::new();
         if categories.iter().any(|category| category == "Contra Dance") {
@@ -214,9 +218,9 @@ fn apply_fixes(event: &mut Event) {
     let details_lower = event.details.as_deref().unwrap_or_default().to_lowercase();
     if event.price.is_none() {
         if details_lower.contains("admission by donation") {
-            event.price = Some("donation".to_string());
+            event.price = Some("donation".into());
         } else if details_lower.contains("cost: free.") {
-            event.price = Some("free".to_string());
+            event.price = Some("free".into());
         }
     }
 
@@ -358,7 +362,7 @@ fn apply_fixes(event: &mut Event) {
                 .insert(0, "https://birdsborocontra.org/our-schedule/".to_string());
             event.workshop = true;
             if event.price.is_none() {
-                event.price = Some("$10-$20".to_string());
+                event.price = Some("$10-$20".into());
             }
         }
         "Blacksburg Contra Dance" => {
@@ -426,7 +430,7 @@ fn apply_fixes(event: &mut Event) {
                 .links
                 .insert(0, "https://www.contranooga.dance/".to_string());
             if event.price.is_none() {
-                event.price = Some("$10-$15".to_string());
+                event.price = Some("$10-$15".into());
             }
         }
         "Chicagoland English Country Dance" => {
@@ -489,8 +493,8 @@ fn apply_fixes(event: &mut Event) {
                 .links
                 .insert(0, "https://cdny.org/calendar-events".to_string());
             event.styles.push(DanceStyle::ScottishCountryDance);
-            if event.price.as_deref() == Some("$10-$20") {
-                event.price = Some("$10-$25".to_string());
+            if event.price == Some("$10-$20".into()) {
+                event.price = Some("$10-$25".into());
             }
         }
         "Contra & Square Dance - Grand River Folk Art Society" => {
@@ -616,16 +620,16 @@ fn apply_fixes(event: &mut Event) {
             event.country = "USA".to_string();
             event.state = Some("FL".to_string());
             event.city = "Gainesville".to_string();
-            if event.price.as_deref() == Some("$5") {
-                event.price = Some("$10".to_string());
+            if event.price == Some("$5".into()) {
+                event.price = Some("$10".into());
             }
         }
         "English Country Dance for all" if event.city == "New York" => {
             event
                 .links
                 .insert(0, "https://cdny.org/calendar-events".to_string());
-            if event.price.as_deref() == Some("$10-$20") {
-                event.price = Some("$10-$25".to_string());
+            if event.price == Some("$10-$20".into()) {
+                event.price = Some("$10-$25".into());
             }
         }
         "First Saturday Contra at Guiding Star Grange"
@@ -667,7 +671,7 @@ fn apply_fixes(event: &mut Event) {
             );
             event.social = false;
             if event.price.is_none() {
-                event.price = Some("free".to_string());
+                event.price = Some("free".into());
             }
         }
         "Contra for a Cause"
@@ -725,7 +729,7 @@ fn apply_fixes(event: &mut Event) {
                 "https://sites.google.com/corp/site/svcwed/home-1/2025-practice-schedule"
                     .to_string(),
             );
-            event.price = Some("free".to_string());
+            event.price = Some("free".into());
         }
         "Folklore Society of Greater Washington (FSGW) English Country Dance" => {
             event.name = "FSGW English Country Dance".to_string();
@@ -805,8 +809,8 @@ fn apply_fixes(event: &mut Event) {
         "Goshen, IN Contra Dance" | "Goshen Community Contra Dance" => {
             event.name = "Goshen Community Contra Dance".to_string();
             event.links.insert(0, "https://godancing.org/".to_string());
-            if event.price.as_deref() == Some("$3-$18") {
-                event.price = Some("$3-$8".to_string());
+            if event.price == Some("$3-$18".into()) {
+                event.price = Some("$3-$8".into());
             }
         }
         "Grand Junction ECD" => {
@@ -1005,7 +1009,7 @@ fn apply_fixes(event: &mut Event) {
                 .insert(0, "https://www.montpeliercontradance.org/".to_string());
         }
         "Monday Cincinnati Contra Dance" | "Cincinnati Monday Night Contra" => {
-            event.price = Some("$2-$5".to_string());
+            event.price = Some("$2-$5".into());
             if event.state.is_none() {
                 event.state = Some("OH".to_string());
                 event.country = "USA".to_string();
@@ -1050,8 +1054,8 @@ fn apply_fixes(event: &mut Event) {
                 "https://mysticpiedance.wixsite.com/mystic-pie-dance".to_string(),
             );
             event.name = event.name.trim_end_matches('!').to_string();
-            if event.price.as_deref() == Some("$3-$10") {
-                event.price = Some("$7-$10".to_string());
+            if event.price == Some("$3-$10".into()) {
+                event.price = Some("$7-$10".into());
             }
         }
         "Nashville English Country Dance"
@@ -1090,7 +1094,7 @@ fn apply_fixes(event: &mut Event) {
                 .links
                 .insert(0, "https://oldfarmersball.com/thursday-dance/".to_string());
             if event.price.is_none() {
-                event.price = Some("$10-$12".to_string());
+                event.price = Some("$10-$12".into());
             }
         }
         "Orlando Contra Dance" => {
@@ -1228,7 +1232,7 @@ fn apply_fixes(event: &mut Event) {
             event
                 .links
                 .insert(0, "https://folkmads.org/events/".to_string());
-            event.price = Some("$10".to_string());
+            event.price = Some("$10".into());
         }
         "Saturday Contra in Cincinnati" => {
             event.links.insert(
@@ -1349,7 +1353,7 @@ fn apply_fixes(event: &mut Event) {
             event.state = Some("FL".to_string());
             event.city = "Gainesville".to_string();
             event.workshop = true;
-            event.price = Some("$5-$10".to_string());
+            event.price = Some("$5-$10".into());
         }
         "Third Sunday English Regency Dancing & Tea in Van Nuys, CA" => {
             event.name = "Third Sunday English Regency Dancing & Tea".to_string();
@@ -1388,14 +1392,14 @@ fn apply_fixes(event: &mut Event) {
                 .insert(0, "https://norfolkcontradance.com/".to_string());
             event.workshop = true;
             if event.price.is_none() {
-                event.price = Some("$12-$15".to_string());
+                event.price = Some("$12-$15".into());
             }
         }
         "Tucson Contra Dance" => {
             event
                 .links
                 .insert(0, "https://tucsoncontradancers.org/".to_string());
-            event.price = Some("$5-$10".to_string());
+            event.price = Some("$5-$10".into());
         }
         "Village Green English Country Dancers' Weekly Classes" => {
             event.links.insert(
@@ -1418,7 +1422,7 @@ fn apply_fixes(event: &mut Event) {
                 .links
                 .insert(0, "https://austinbarndancers.org/".to_string());
             if event.price.is_none() {
-                event.price = Some("free".to_string());
+                event.price = Some("free".into());
             }
         }
         "Williamsburg Tuesday Night English Dance" => {
@@ -1432,7 +1436,7 @@ fn apply_fixes(event: &mut Event) {
                 .links
                 .insert(0, "https://northberkshiredance.org/".to_string());
             if event.price.is_none() {
-                event.price = Some("$12-$20".to_string());
+                event.price = Some("$12-$20".into());
             }
         }
         "Wooster Contra Dance" => {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::{EventParts, IcalendarSource};
-use crate::model::{dancestyle::DanceStyle, event::Event};
+use crate::model::{dancestyle::DanceStyle, event::Event, status::Status};
 use eyre::Report;
 
 pub struct Cologne;
@@ -47,7 +47,7 @@ impl IcalendarSource for Cologne {
 
     fn fixup(mut event: Event) -> Option<Event> {
         if event.name.contains("fällt aus") {
-            event.cancelled = true;
+            event.status = Status::Cancelled;
         }
         Some(event)
     }
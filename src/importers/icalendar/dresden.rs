@@ -15,7 +15,7 @@
 use super::{EventParts, IcalendarSource, import_new_events};
 use crate::{
     importers::combine_events,
-    model::{dancestyle::DanceStyle, event::Event, events::Events},
+    model::{dancestyle::DanceStyle, event::Event, events::Events, registries::Registries},
 };
 use eyre::Report;
 use regex::Regex;
@@ -24,11 +24,11 @@ const ORGANISATION: &str = "Folktanz Dresden e.V.";
 
 /// Imports events from both Dresden sources, preserving the given previously imported events if
 /// appropriate.
-pub async fn import_events(old_events: Events) -> Result<Events, Report> {
-    let mut new_events = import_new_events::<Dresden>().await?;
+pub async fn import_events(old_events: Events, registries: &Registries) -> Result<Events, Report> {
+    let mut new_events = import_new_events::<Dresden>(registries).await?;
     new_events
         .events
-        .extend(import_new_events::<DresdenWeekly>().await?.events);
+        .extend(import_new_events::<DresdenWeekly>(registries).await?.events);
     new_events.sort();
     Ok(combine_events(old_events, new_events))
 }
@@ -66,14 +66,14 @@ impl IcalendarSource for Freiburg {
         match event.name.as_str() {
             "Montagstanzen: Fortgeschrittene" | "Monstagstanzen: Fortgeschrittene" => {
                 event.name = "Monday Dancing: Advanced".to_string();
-                event.price = Some("donation".to_string());
+                event.price = Some("donation".into());
             }
             "Montagstanzen:  Anfänger"
             | "Montagstanzen: Anfänger"
             | "Montagstanz: Anfänger"
             | "Monstagstanzen: Anfänger" => {
                 event.name = "Monday Dancing: Beginners".to_string();
-                event.price = Some("donation".to_string());
+                event.price = Some("donation".into());
             }
             _ => {}
         }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::{EventParts, IcalendarSource};
-use crate::model::{dancestyle::DanceStyle, event::Event};
+use crate::model::{dancestyle::DanceStyle, event::Event, status::Status};
 use eyre::Report;
 use log::warn;
 
@@ -106,10 +106,10 @@ impl IcalendarSource for Skandia {
                 .to_lowercase()
                 .contains("no class")
         {
-            event.cancelled = true;
+            event.status = Status::Cancelled;
         }
         if name_lower.contains("free") && event.price.is_none() {
-            event.price = Some("free".to_string());
+            event.price = Some("free".into());
         }
         match event.name.as_str() {
             "Beginner Scandinavia dance classes near UW" => {
@@ -76,9 +76,9 @@ impl IcalendarSource for Spreefolk {
                 if details.contains(
                     "Der Eintritt ist frei – Spenden für Tanz und Musik sind aber willkommen",
                 ) {
-                    event.price = Some("donation".to_string());
+                    event.price = Some("donation".into());
                 } else if details.contains("Der Eintritt ist frei") {
-                    event.price = Some("free".to_string());
+                    event.price = Some("free".into());
                 }
             }
 
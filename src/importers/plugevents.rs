@@ -15,11 +15,18 @@
 mod types;
 
 use self::types::{Event, EventFormat, EventList, InterestTag};
-use super::{bands::BANDS, lowercase_matches};
-use crate::model::{
-    dancestyle::DanceStyle,
-    event::{self, EventTime},
-    events::Events,
+use super::lowercase_matches;
+use crate::{
+    markdown,
+    model::{
+        attendance::AttendanceMode,
+        dancestyle::DanceStyle,
+        event::{self, EventTime},
+        events::Events,
+        price::Price,
+        registries::Registries,
+        status::Status,
+    },
 };
 use chrono::Timelike;
 use eyre::{Report, eyre};
@@ -35,19 +42,23 @@ pub async fn events(token: &str) -> Result<Vec<Event>, Report> {
     Ok(events.events)
 }
 
-pub async fn import_events(token: &str) -> Result<Events, Report> {
+pub async fn import_events(token: &str, registries: &Registries) -> Result<Events, Report> {
     let events = events(token).await?;
     let style = DanceStyle::Balfolk;
 
     Ok(Events {
         events: events
             .iter()
-            .filter_map(|event| convert(event, style).transpose())
+            .filter_map(|event| convert(event, style, registries).transpose())
             .collect::<Result<_, _>>()?,
     })
 }
 
-fn convert(event: &Event, default_style: DanceStyle) -> Result<Option<event::Event>, Report> {
+fn convert(
+    event: &Event,
+    default_style: DanceStyle,
+    registries: &Registries,
+) -> Result<Option<event::Event>, Report> {
     let Some(venue_locale) = &event.venue_locale else {
         eprintln!("Event \"{}\" has no venueLocale, skipping.", event.name);
         return Ok(None);
@@ -252,7 +263,7 @@ fn convert(event: &Event, default_style: DanceStyle) -> Result<Option<event::Eve
 
     let name_lower = event.name.to_lowercase();
     let description_lower = event.description.to_lowercase();
-    let mut bands = lowercase_matches(BANDS, &description_lower, &name_lower);
+    let mut bands = lowercase_matches(&registries.bands.bands, &description_lower, &name_lower);
     bands.extend(
         event
             .featured_participants
@@ -289,8 +300,9 @@ fn convert(event: &Event, default_style: DanceStyle) -> Result<Option<event::Eve
     }
 
     Ok(Some(event::Event {
+        id: None,
         name,
-        details: Some(event.description.clone()),
+        details: Some(markdown::from_html(&event.description)),
         links: vec![event.plug_url.clone()],
         time: EventTime::DateTime {
             start: event
@@ -306,17 +318,35 @@ fn convert(event: &Event, default_style: DanceStyle) -> Result<Option<event::Eve
                 .with_timezone(&event.timezone)
                 .fixed_offset(),
         },
+        timezone: Some(event.timezone),
         country,
         state: None,
         city,
+        venue_id: None,
+        venue: event.venue_name.clone(),
+        address: event.venue_address.clone(),
+        latitude: event.venue_lat,
+        longitude: event.venue_lon,
+        attendance: AttendanceMode::InPerson,
+        stream_url: None,
         styles,
         workshop,
         social,
         bands,
         callers: vec![],
-        price: format_price(event),
+        teachers: vec![],
+        sessions: vec![],
+        level: None,
+        role_terms: None,
+        no_partner_needed: false,
+        family_friendly: false,
+        accessibility: vec![],
+        price: format_price(event).map(Price::from),
         organisation: event.published_by_name.as_deref().map(fix_organisation),
-        cancelled: false,
+        status: Status::Scheduled,
+        rescheduled_to: None,
+        recurrence: None,
+        series: None,
         source: None,
     }))
 }
@@ -15,7 +15,9 @@
 mod types;
 
 use self::types::Event;
-use crate::model::{dancestyle::DanceStyle, event, events::Events};
+use crate::model::{
+    attendance::AttendanceMode, dancestyle::DanceStyle, event, events::Events, status::Status,
+};
 use chrono::NaiveDate;
 use eyre::Report;
 use log::warn;
@@ -82,12 +84,12 @@ fn convert(event: &Event) -> Result<Option<event::Event>, Report> {
     }
 
     let links;
-    let cancelled;
+    let status;
     if event.url.contains("CANCELLED") {
-        cancelled = true;
+        status = Status::Cancelled;
         links = vec![];
     } else {
-        cancelled = false;
+        status = Status::Scheduled;
         links = vec![event.url.to_owned()];
     }
 
@@ -147,21 +149,40 @@ fn convert(event: &Event) -> Result<Option<event::Event>, Report> {
     };
 
     Ok(Some(event::Event {
+        id: None,
         name,
         details: None,
         links,
         time,
+        timezone: None,
         country,
         state,
         city,
+        venue_id: None,
+        venue: None,
+        address: None,
+        latitude: None,
+        longitude: None,
+        attendance: AttendanceMode::InPerson,
+        stream_url: None,
         styles: vec![DanceStyle::Contra],
         workshop: true,
         social: true,
         bands,
         callers: event.callers.to_owned(),
+        teachers: vec![],
+        sessions: vec![],
+        level: None,
+        role_terms: None,
+        no_partner_needed: false,
+        family_friendly: false,
+        accessibility: vec![],
         price: None,
         organisation: Some(organisation.to_string()),
-        cancelled,
+        status,
+        rescheduled_to: None,
+        recurrence: None,
+        series: None,
         source: None,
     }))
 }
@@ -16,9 +16,11 @@ mod types;
 
 use self::types::{EventRecord, Eventlist, Style};
 use crate::model::{
+    attendance::AttendanceMode,
     dancestyle::DanceStyle,
     event::{Event, EventTime},
     events::Events,
+    status::Status,
 };
 use chrono::NaiveDate;
 use eyre::Report;
@@ -118,11 +120,13 @@ fn convert(event: &EventRecord) -> Option<Event> {
 
     let mut name = format!("{} in {}", bands.join(" & "), city);
     let bands = bands.into_iter().filter(|band| band != "TBA").collect();
-    let mut cancelled = false;
+    let mut status = Status::Scheduled;
     if let Some(event) = event.event_collection.event.first() {
         if event.value.starts_with('[') {
-            if event.value == "[Cancelled]" || event.value == "[Postponed]" {
-                cancelled = true;
+            if event.value == "[Cancelled]" {
+                status = Status::Cancelled;
+            } else if event.value == "[Postponed]" {
+                status = Status::Postponed;
             }
             details = Some(event.value.clone());
         } else {
@@ -174,21 +178,40 @@ fn convert(event: &EventRecord) -> Option<Event> {
         None
     } else {
         Some(Event {
+            id: None,
             name,
             details,
             links,
             time: parse_date(&event.canonical_date.isoformat),
+            timezone: None,
             country: "UK".to_string(),
             state: None,
             city,
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles,
             workshop: false,
             social: true,
             bands,
             callers,
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: Some("Webfeet".to_string()),
-            cancelled,
+            status,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         })
     }
@@ -20,12 +20,15 @@ mod extractors;
 mod github;
 mod icalendar;
 mod importers;
+mod markdown;
 mod model;
 mod util;
 
 use crate::{
     config::Config,
-    controllers::{add, bands, callers, cities, edit, index, organisations, reload},
+    controllers::{
+        add, bands, callers, cities, edit, event, index, organisations, reload, teachers,
+    },
     diff::diff_markdown,
     errors::internal_error,
     importers::{
@@ -40,7 +43,7 @@ use crate::{
         },
         plugevents, trycontra, webfeet,
     },
-    model::events::Events,
+    model::{events::Events, registries::Registries},
 };
 use axum::{
     Router,
@@ -78,6 +81,9 @@ enum Command {
     Validate { events: Option<String> },
     /// Loads all events from the given file, directory or URL, and prints them as a single file.
     ///
+    /// Events are printed as they are stored, without expanding recurring events or filling in
+    /// details from venues and sessions.
+    ///
     /// If no path or URL is specified, uses the one configured in the config file.
     #[command(name = "cat")]
     Concatenate { events: Option<String> },
@@ -187,7 +193,7 @@ async fn load_events(path: Option<&str>) -> Result<Events, Report> {
         Events::load_events(path).await
     } else {
         let config = Config::from_file()?;
-        Events::load_events(&config.events).await
+        Events::load_events_with_window(&config.events, config.recurrence_window).await
     }
 }
 
@@ -199,7 +205,11 @@ async fn validate(path: Option<&str>) -> Result<(), Report> {
 }
 
 async fn concatenate(path: Option<&str>) -> Result<(), Report> {
-    let mut events = load_events(path).await?;
+    let path = match path {
+        Some(path) => path.to_owned(),
+        None => Config::from_file()?.events,
+    };
+    let mut events = Events::load_stored_events(&path).await?;
     events.sort();
     print!("{}", serde_yaml::to_string(&events)?);
     Ok(())
@@ -207,7 +217,11 @@ async fn concatenate(path: Option<&str>) -> Result<(), Report> {
 
 /// Load the given file of events, and output them again sorted by start time, country then city.
 async fn sort(path: &str) -> Result<(), Report> {
-    let mut events = Events::load_events(path).await?;
+    // Keep events as they are stored, so that recurring events aren't expanded and details from
+    // venues and sessions aren't repeated.
+    let mut events = Events::load_stored_events(path).await?;
+    // Give new events an ID once, when they are written back, so that it stays the same.
+    events.assign_ids();
     // Sort by date then location.
     events.sort();
     print_events(&events)?;
@@ -232,27 +246,37 @@ async fn import(source: ImportSource, filename: &Path) -> Result<(), Report> {
     } else {
         Events::default()
     };
+    // Known bands and callers are found in the same directory as the events or its parents.
+    let registries = Registries::load_for_file(filename)?;
     let events = match source {
         ImportSource::Balbende => folkbalbende::import_events().await?,
-        ImportSource::BalfolkCambridge => import_events::<BalfolkCambridge>(old_events).await?,
-        ImportSource::BalfolkCz => import_events::<BalfolkCz>(old_events).await?,
-        ImportSource::Balfolknl => import_events::<BalfolkNl>(old_events).await?,
-        ImportSource::Boulder => import_events::<Boulder>(old_events).await?,
-        ImportSource::BristolContra => import_events::<BristolContra>(old_events).await?,
-        ImportSource::Burghausen => import_events::<Burghausen>(old_events).await?,
-        ImportSource::Cdss => import_events::<Cdss>(old_events).await?,
-        ImportSource::CeilidhClub => import_events::<CeilidhClub>(old_events).await?,
-        ImportSource::Cologne => import_events::<Cologne>(old_events).await?,
-        ImportSource::Contrabridge => import_events::<Contrabridge>(old_events).await?,
-        ImportSource::Dresden => dresden::import_events(old_events).await?,
-        ImportSource::Freiburg => import_events::<Freiburg>(old_events).await?,
-        ImportSource::FridayFolk => import_events::<FridayFolk>(old_events).await?,
-        ImportSource::Kalender => import_events::<Kalender>(old_events).await?,
-        ImportSource::Marburg => import_events::<Marburg>(old_events).await?,
-        ImportSource::Skandia => import_events::<Skandia>(old_events).await?,
-        ImportSource::Spreefolk => import_events::<Spreefolk>(old_events).await?,
-        ImportSource::Stroud => import_events::<Stroud>(old_events).await?,
-        ImportSource::LancasterContra => import_events::<LancasterContra>(old_events).await?,
+        ImportSource::BalfolkCambridge => {
+            import_events::<BalfolkCambridge>(old_events, &registries).await?
+        }
+        ImportSource::BalfolkCz => import_events::<BalfolkCz>(old_events, &registries).await?,
+        ImportSource::Balfolknl => import_events::<BalfolkNl>(old_events, &registries).await?,
+        ImportSource::Boulder => import_events::<Boulder>(old_events, &registries).await?,
+        ImportSource::BristolContra => {
+            import_events::<BristolContra>(old_events, &registries).await?
+        }
+        ImportSource::Burghausen => import_events::<Burghausen>(old_events, &registries).await?,
+        ImportSource::Cdss => import_events::<Cdss>(old_events, &registries).await?,
+        ImportSource::CeilidhClub => import_events::<CeilidhClub>(old_events, &registries).await?,
+        ImportSource::Cologne => import_events::<Cologne>(old_events, &registries).await?,
+        ImportSource::Contrabridge => {
+            import_events::<Contrabridge>(old_events, &registries).await?
+        }
+        ImportSource::Dresden => dresden::import_events(old_events, &registries).await?,
+        ImportSource::Freiburg => import_events::<Freiburg>(old_events, &registries).await?,
+        ImportSource::FridayFolk => import_events::<FridayFolk>(old_events, &registries).await?,
+        ImportSource::Kalender => import_events::<Kalender>(old_events, &registries).await?,
+        ImportSource::Marburg => import_events::<Marburg>(old_events, &registries).await?,
+        ImportSource::Skandia => import_events::<Skandia>(old_events, &registries).await?,
+        ImportSource::Spreefolk => import_events::<Spreefolk>(old_events, &registries).await?,
+        ImportSource::Stroud => import_events::<Stroud>(old_events, &registries).await?,
+        ImportSource::LancasterContra => {
+            import_events::<LancasterContra>(old_events, &registries).await?
+        }
         ImportSource::Trycontra => trycontra::import_events().await?,
         ImportSource::Webfeet => webfeet::import_events().await?,
     };
@@ -261,7 +285,9 @@ async fn import(source: ImportSource, filename: &Path) -> Result<(), Report> {
 }
 
 async fn import_plug_events(token: &str, filename: &Path) -> Result<(), Report> {
-    let events = plugevents::import_events(token).await?;
+    // Events are written to subdirectories of "events", so look for known bands there.
+    let registries = Registries::load_for_path("events")?;
+    let events = plugevents::import_events(token, &registries).await?;
     write_by_country(events, filename)?;
     Ok(())
 }
@@ -311,11 +337,14 @@ async fn find_duplicates() -> Result<(), Report> {
 
 async fn serve() -> Result<(), Report> {
     let config = Arc::new(Config::from_file()?);
-    let events = Events::load_events(&config.events).await?;
+    let events = Events::load_events_with_window(&config.events, config.recurrence_window).await?;
     let events = Arc::new(Mutex::new(events));
+    let registries = Registries::load_for_path(&config.events)?;
+    let registries = Arc::new(Mutex::new(registries));
     let state = AppState {
         config: config.clone(),
         events,
+        registries,
     };
 
     let app = Router::new()
@@ -331,10 +360,24 @@ async fn serve() -> Result<(), Report> {
         .route("/add", post(add::submit))
         .route("/edit", get(edit::edit))
         .route("/edit", post(edit::submit))
+        .route("/event/{id}", get(event::event))
+        .route("/event/{id}/event.ics", get(event::event_ics))
+        .route("/event/{id}/lineup", get(event::event_lineup))
+        .route("/event/{id}/lineup.json", get(event::event_lineup_json))
         .route("/bands", get(bands::bands))
+        .route("/band/{slug}", get(bands::band))
+        .route("/band/{slug}/events.ics", get(bands::band_ics))
         .route("/callers", get(callers::callers))
+        .route("/caller/{slug}", get(callers::caller))
+        .route("/caller/{slug}/events.ics", get(callers::caller_ics))
+        .route("/teachers", get(teachers::teachers))
         .route("/cities", get(cities::cities))
         .route("/organisations", get(organisations::organisations))
+        .route("/organisation/{slug}", get(organisations::organisation))
+        .route(
+            "/organisation/{slug}/events.ics",
+            get(organisations::organisation_ics),
+        )
         .route("/reload", post(reload::reload))
         .route_service(
             "/robots.txt",
@@ -363,6 +406,7 @@ async fn serve() -> Result<(), Report> {
 struct AppState {
     config: Arc<Config>,
     events: Arc<Mutex<Events>>,
+    registries: Arc<Mutex<Registries>>,
 }
 
 /// Returns the JSON schema for events.
@@ -0,0 +1,150 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ammonia::Builder;
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, html::push_html};
+use regex::Regex;
+use std::{collections::HashSet, sync::LazyLock};
+
+/// The HTML tags which rendered Markdown may contain. Anything else, such as images and headings,
+/// is reduced to its text.
+const ALLOWED_TAGS: [&str; 13] = [
+    "a",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "em",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "strong",
+    "ul",
+];
+
+/// A regular expression matching an HTML start or end tag.
+static HTML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"</?[a-zA-Z][^<>]*>").unwrap());
+
+fn parser(markdown: &str) -> Parser<'_> {
+    Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH)
+}
+
+/// Renders the given Markdown to sanitised HTML.
+///
+/// Any raw HTML in the Markdown is escaped and shown as text rather than being interpreted.
+pub fn to_html(markdown: &str) -> String {
+    let events = parser(markdown).map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        _ => event,
+    });
+    let mut html = String::new();
+    push_html(&mut html, events);
+    Builder::default()
+        .tags(HashSet::from(ALLOWED_TAGS))
+        .clean(&html)
+        .to_string()
+}
+
+/// Strips the Markdown formatting from the given text, leaving plain text with a line for each
+/// paragraph or list item. Link URLs are given in brackets after the link text, and any raw HTML
+/// tags are removed.
+pub fn to_plain_text(markdown: &str) -> String {
+    let mut text = String::new();
+    // The URL of the link currently being output, and where its text starts.
+    let mut link = None;
+    for event in parser(markdown) {
+        match event {
+            Event::Text(s) | Event::Code(s) => text.push_str(&s),
+            Event::Html(html) => text.push_str(&HTML_TAG.replace_all(&html, "")),
+            Event::SoftBreak | Event::HardBreak => text.push('\n'),
+            Event::Start(Tag::Item) => text.push_str("- "),
+            Event::Start(Tag::Link { dest_url, .. }) => link = Some((dest_url, text.len())),
+            Event::End(TagEnd::Link) => {
+                if let Some((url, start)) = link.take()
+                    && text[start..] != *url
+                {
+                    text.push_str(&format!(" ({url})"));
+                }
+            }
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::Item
+                | TagEnd::CodeBlock
+                | TagEnd::HtmlBlock,
+            ) if !text.ends_with('\n') => text.push('\n'),
+            _ => {}
+        }
+    }
+    text.trim().to_owned()
+}
+
+/// Converts HTML, such as an imported event description, to Markdown.
+///
+/// Text without any HTML tags is returned unchanged, so that its line breaks are kept.
+pub fn from_html(html: &str) -> String {
+    if HTML_TAG.is_match(html) {
+        html2md::parse_html(html).trim().to_owned()
+    } else {
+        html.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_html() {
+        assert_eq!(
+            to_html("Bal with **live music**.\n\n- [Tickets](https://example.com/)"),
+            "<p>Bal with <strong>live music</strong>.</p>\n<ul>\n<li><a href=\"https://example.com/\" \
+             rel=\"noopener noreferrer\">Tickets</a></li>\n</ul>\n"
+        );
+        assert_eq!(
+            to_html("<script>alert(1)</script>"),
+            "&lt;script&gt;alert(1)&lt;/script&gt;"
+        );
+        assert_eq!(
+            to_html("[x](javascript:alert(1)) <b>bold</b>"),
+            "<p><a rel=\"noopener noreferrer\">x</a> &lt;b&gt;bold&lt;/b&gt;</p>\n"
+        );
+    }
+
+    #[test]
+    fn strip_to_plain_text() {
+        assert_eq!(
+            to_plain_text(
+                "Bal with **live music**.\nDoors at 8.\n\n- [Tickets](https://example.com/)\n- \
+                 <https://example.com/info>"
+            ),
+            "Bal with live music.\nDoors at 8.\n- Tickets (https://example.com/)\n- \
+             https://example.com/info"
+        );
+        assert_eq!(to_plain_text("<p>Old <b>HTML</b></p>"), "Old HTML");
+    }
+
+    #[test]
+    fn convert_from_html() {
+        assert_eq!(
+            from_html(
+                "<p>Bal with <b>live music</b></p><p><a href=\"https://example.com/\">Tickets</a></p>"
+            ),
+            "Bal with **live music**\n\n[Tickets](https://example.com/)"
+        );
+        assert_eq!(from_html("Line one\nLine two"), "Line one\nLine two");
+    }
+}
@@ -12,7 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod attendance;
 pub mod dancestyle;
 pub mod event;
 pub mod events;
 pub mod filters;
+pub mod inclusivity;
+pub mod organisations;
+pub mod performers;
+pub mod price;
+pub mod recurrence;
+pub mod registries;
+pub mod series;
+pub mod session;
+pub mod status;
+pub mod venues;
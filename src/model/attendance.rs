@@ -0,0 +1,83 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use enum_iterator::{Sequence, all};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// How people can attend an event.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Deserialize,
+    Eq,
+    Hash,
+    JsonSchema,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Sequence,
+    Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AttendanceMode {
+    /// The event takes place at a physical venue.
+    #[default]
+    InPerson,
+    /// The event takes place only online, so has no physical location.
+    Online,
+    /// The event takes place at a physical venue, and can also be joined online.
+    Hybrid,
+}
+
+impl AttendanceMode {
+    pub fn values() -> impl Iterator<Item = Self> {
+        all::<Self>()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::InPerson => "in_person",
+            Self::Online => "online",
+            Self::Hybrid => "hybrid",
+        }
+    }
+
+    pub fn is_in_person(&self) -> bool {
+        *self == Self::InPerson
+    }
+
+    /// Returns whether the event can be attended online.
+    pub fn online(self) -> bool {
+        self != Self::InPerson
+    }
+
+    /// Returns whether the event can be attended at a physical venue.
+    pub fn in_person(self) -> bool {
+        self != Self::Online
+    }
+}
+
+impl Display for AttendanceMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::InPerson => "in person",
+            Self::Online => "online",
+            Self::Hybrid => "in person and online",
+        })
+    }
+}
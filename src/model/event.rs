@@ -12,12 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::dancestyle::DanceStyle;
-use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeDelta, TimeZone, Utc};
+use super::{
+    attendance::AttendanceMode,
+    dancestyle::DanceStyle,
+    inclusivity::{Accessibility, Level, RoleTerms},
+    price::{Price, PriceRepr},
+    recurrence::Recurrence,
+    session::{Session, SessionKind, add_missing},
+    status::{Status, status_or_cancelled},
+};
+use crate::{markdown, util::default_timezone_for};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Offset, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, Serializer};
 use sha1::{Digest, Sha1};
-use std::ops::Not;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// The mean radius of the Earth, for calculating distances.
+const EARTH_RADIUS_KM: f64 = 6371.0;
 
 /// The prefix which Facebook event URLs start with.
 const FACEBOOK_EVENT_PREFIX: &str = "https://www.facebook.com/events/";
@@ -26,11 +40,15 @@ const CDSS_EVENT_PREFIX: &str = "https://cdss.org/event/";
 const PLUG_EVENTS_PREFIX: &str = "https://www.plug.events/event/";
 const KALENDER_EVENT_PREFIX: &str = "https://kalender.digital/574d155c91900caea879/event/";
 
-#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, PartialEq, Serialize)]
+#[cfg_attr(test, derive(Default))]
 pub struct Event {
+    /// A persistent unique identifier for the event, which stays the same when it is edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /// The name of the event.
     pub name: String,
-    /// More details describing the event.
+    /// More details describing the event, in Markdown. Any raw HTML is shown as text.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
     /// URLs with more information about the event, including the Facebook event page if any.
@@ -38,11 +56,43 @@ pub struct Event {
     pub links: Vec<String>,
     #[serde(flatten)]
     pub time: EventTime,
+    /// The IANA timezone in which the event takes place, e.g. "Europe/London". If the event has a
+    /// start and end time then their offsets must match this timezone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<String>")]
+    pub timezone: Option<Tz>,
+    /// The country in which the event takes place. This may be omitted if it is given by the venue
+    /// with `venue_id`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub country: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
+    /// The city in which the event takes place. This may be omitted if it is given by the venue with
+    /// `venue_id`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub city: String,
-    // TODO: What about full address?
+    /// The ID of a venue in a venues file, from which the venue name, address, city, state, country
+    /// and coordinates are filled in unless the event gives them itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub venue_id: Option<String>,
+    /// The name of the venue where the event takes place, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub venue: Option<String>,
+    /// The full street address of the venue, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// The latitude of the venue in degrees north, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    /// The longitude of the venue in degrees east, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    /// Whether the event takes place in person, online or both.
+    #[serde(default, skip_serializing_if = "AttendanceMode::is_in_person")]
+    pub attendance: AttendanceMode,
+    /// The URL from which an online or hybrid event can be joined, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream_url: Option<String>,
     /// The dance styles included in the event.
     #[serde(default)]
     pub styles: Vec<DanceStyle>,
@@ -58,15 +108,56 @@ pub struct Event {
     /// The names of the callers calling at the event, if applicable.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub callers: Vec<String>,
+    /// The names of the teachers leading workshops at the event, if applicable.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub teachers: Vec<String>,
+    /// The parts of the event with their own times, if it has several, such as a workshop before
+    /// a social dance. If there are any then `workshop` and `social` are derived from their kinds,
+    /// and their bands, callers and teachers are added to those of the event.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sessions: Vec<Session>,
+    /// The level of dancing experience the event is aimed at, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<Level>,
+    /// The terms used for dancers' roles, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role_terms: Option<RoleTerms>,
+    /// People can come without a partner, and will be able to find one to dance with.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub no_partner_needed: bool,
+    /// The event welcomes children and families.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub family_friendly: bool,
+    /// The accessibility features of the venue, as far as they are known.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accessibility: Vec<Accessibility>,
     /// The price or price range of the event, if available.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub price: Option<String>,
+    #[schemars(with = "Option<PriceRepr>")]
+    pub price: Option<Price>,
     /// The organisation who run the event.
     #[serde(default)]
     pub organisation: Option<String>,
-    /// Whether the event has been cancelled.
-    #[serde(default, skip_serializing_if = "Not::not")]
-    pub cancelled: bool,
+    /// Whether the event is going ahead as planned. The legacy `cancelled: true` is also
+    /// accepted.
+    #[serde(
+        default,
+        alias = "cancelled",
+        skip_serializing_if = "Status::is_scheduled",
+        deserialize_with = "status_or_cancelled"
+    )]
+    pub status: Status,
+    /// For a rescheduled event, the ID of the event at its new time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rescheduled_to: Option<String>,
+    /// A rule for repeating the event, starting from the instance given by its time. Recurring
+    /// events are expanded into an event for each instance when they are loaded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+    /// For an instance of a recurring event, the series it was expanded from.
+    #[serde(skip)]
+    #[schemars(skip)]
+    pub series: Option<Arc<Event>>,
     /// The name of the file in which this event is stored.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
@@ -90,6 +181,16 @@ pub enum EventTime {
     },
 }
 
+#[cfg(test)]
+impl Default for EventTime {
+    fn default() -> Self {
+        Self::DateOnly {
+            start_date: NaiveDate::default(),
+            end_date: NaiveDate::default(),
+        }
+    }
+}
+
 fn serialize_time<S: Serializer>(
     time: &DateTime<FixedOffset>,
     serializer: S,
@@ -130,6 +231,17 @@ impl EventTime {
             EventTime::DateTime { start, end: _ } => start.naive_local().date(),
         }
     }
+
+    /// Gets the end date in the event's local timezone.
+    pub fn end_date(&self) -> NaiveDate {
+        match self {
+            EventTime::DateOnly {
+                start_date: _,
+                end_date,
+            } => *end_date,
+            EventTime::DateTime { start: _, end } => end.naive_local().date(),
+        }
+    }
 }
 
 impl Event {
@@ -141,11 +253,35 @@ impl Event {
         if self.name.is_empty() {
             problems.push("Must have a name.");
         }
-        if self.country.is_empty() {
-            problems.push("Must specify a country.");
+        // Online events don't need a physical location.
+        if self.attendance.in_person() {
+            if self.country.is_empty() {
+                problems.push("Must specify a country.");
+            }
+            if self.city.is_empty() {
+                problems.push("Must specify a city.");
+            }
         }
-        if self.city.is_empty() {
-            problems.push("Must specify a city.");
+        if let Some(stream_url) = &self.stream_url {
+            if !self.attendance.online() {
+                problems.push("Only online or hybrid events may have a stream URL.");
+            }
+            if !stream_url.starts_with("https://") && !stream_url.starts_with("http://") {
+                problems.push("Stream URL must start with http:// or https://.");
+            }
+        }
+
+        match (self.latitude, self.longitude) {
+            (Some(latitude), Some(longitude)) => {
+                if !(-90.0..=90.0).contains(&latitude) {
+                    problems.push("Latitude must be between -90 and 90 degrees.");
+                }
+                if !(-180.0..=180.0).contains(&longitude) {
+                    problems.push("Longitude must be between -180 and 180 degrees.");
+                }
+            }
+            (None, None) => {}
+            _ => problems.push("Must specify both latitude and longitude, or neither."),
         }
 
         if !self.workshop && !self.social {
@@ -165,6 +301,12 @@ impl Event {
                 if start > end {
                     problems.push("Start must be before or equal to end.");
                 }
+                if let Some(timezone) = self.timezone
+                    && (start.with_timezone(&timezone).offset().fix() != *start.offset()
+                        || end.with_timezone(&timezone).offset().fix() != *end.offset())
+                {
+                    problems.push("Start and end offsets must match the timezone.");
+                }
             }
         }
 
@@ -172,6 +314,22 @@ impl Event {
             problems.push("Must include at least one style of dance.");
         }
 
+        if let Some(price) = &self.price {
+            problems.extend(price.validate());
+        }
+
+        for session in &self.sessions {
+            problems.extend(session.validate(&self.time));
+        }
+
+        if self.rescheduled_to.is_some() && self.status != Status::Rescheduled {
+            problems.push("Only rescheduled events may give a new event.");
+        }
+
+        if let Some(recurrence) = &self.recurrence {
+            problems.extend(recurrence.validate(self));
+        }
+
         problems
     }
 
@@ -201,6 +359,16 @@ impl Event {
             callers.sort();
             callers.dedup();
 
+            let mut teachers = self.teachers.clone();
+            teachers.extend(other.teachers.clone());
+            teachers.sort();
+            teachers.dedup();
+
+            let mut accessibility = self.accessibility.clone();
+            accessibility.extend(other.accessibility.clone());
+            accessibility.sort();
+            accessibility.dedup();
+
             let details = match (&self.details, &other.details) {
                 (None, None) => None,
                 (Some(d), None) | (None, Some(d)) => Some(d.clone()),
@@ -221,26 +389,66 @@ impl Event {
                 self.name.clone()
             };
 
-            let price = merge_strings(&self.price, &other.price);
-            let organisation = merge_strings(&self.organisation, &other.organisation);
-            let source = merge_strings(&self.source, &other.source);
+            let venue_id = merge_options(&self.venue_id, &other.venue_id);
+            let venue = merge_options(&self.venue, &other.venue);
+            let address = merge_options(&self.address, &other.address);
+            let (latitude, longitude) =
+                merge_options(&self.coordinates(), &other.coordinates()).unzip();
+            let price = merge_options(&self.price, &other.price);
+            let organisation = merge_options(&self.organisation, &other.organisation);
+            let source = merge_options(&self.source, &other.source);
+            // If one source lists the event as in person and another as online then it must be both.
+            let attendance = if self.attendance == other.attendance {
+                self.attendance
+            } else {
+                AttendanceMode::Hybrid
+            };
+            // Any change from the scheduled status is more informative, so prefer that.
+            let status = if self.status.is_scheduled() {
+                other.status
+            } else {
+                self.status
+            };
 
             Some(Event {
+                id: self.id.clone().or_else(|| other.id.clone()),
                 name,
                 details,
                 links,
                 time: self.time.clone(),
+                timezone: merge_options(&self.timezone, &other.timezone),
                 country: self.country.clone(),
                 state: self.state.clone(),
                 city: self.city.clone(),
+                venue_id,
+                venue,
+                address,
+                latitude,
+                longitude,
+                attendance,
+                stream_url: merge_options(&self.stream_url, &other.stream_url),
                 styles,
                 workshop: self.workshop || other.workshop,
                 social: self.social || other.social,
                 bands,
                 callers,
+                teachers,
+                sessions: if self.sessions.is_empty() {
+                    other.sessions.clone()
+                } else {
+                    self.sessions.clone()
+                },
+                level: merge_options(&self.level, &other.level),
+                role_terms: merge_options(&self.role_terms, &other.role_terms),
+                no_partner_needed: self.no_partner_needed || other.no_partner_needed,
+                family_friendly: self.family_friendly || other.family_friendly,
+                accessibility,
                 price,
                 organisation,
-                cancelled: self.cancelled || other.cancelled,
+                status,
+                rescheduled_to: merge_options(&self.rescheduled_to, &other.rescheduled_to),
+                recurrence: merge_options(&self.recurrence, &other.recurrence),
+                series: merge_options(&self.series, &other.series),
                 source,
             })
         } else {
@@ -248,6 +456,91 @@ impl Event {
         }
     }
 
+    /// For an event with sessions, sets whether it has a workshop and a social from the kinds of its
+    /// sessions, and adds the bands, callers and teachers of its sessions to those of the event.
+    pub fn apply_sessions(&mut self) {
+        if self.sessions.is_empty() {
+            return;
+        }
+        self.workshop = self
+            .sessions
+            .iter()
+            .any(|session| session.kind == SessionKind::Workshop);
+        self.social = self
+            .sessions
+            .iter()
+            .any(|session| session.kind == SessionKind::Social);
+        for session in &self.sessions {
+            add_missing(&mut self.bands, &session.bands);
+            add_missing(&mut self.callers, &session.callers);
+            add_missing(&mut self.teachers, &session.teachers);
+        }
+    }
+
+    /// Returns short descriptions of the event's level, role terms, accessibility and so on, for
+    /// display.
+    pub fn attribute_labels(&self) -> Vec<String> {
+        let mut labels = Vec::new();
+        labels.extend(self.level.map(|level| level.to_string()));
+        labels.extend(self.role_terms.map(|role_terms| role_terms.to_string()));
+        if self.no_partner_needed {
+            labels.push("no partner needed".to_string());
+        }
+        if self.family_friendly {
+            labels.push("family friendly".to_string());
+        }
+        labels.extend(self.accessibility.iter().map(ToString::to_string));
+        labels
+    }
+
+    /// Returns the details rendered from Markdown to sanitised HTML, if there are any.
+    pub fn details_html(&self) -> Option<String> {
+        self.details.as_deref().map(markdown::to_html)
+    }
+
+    /// Returns the details with Markdown formatting stripped, if there are any.
+    pub fn details_text(&self) -> Option<String> {
+        self.details.as_deref().map(markdown::to_plain_text)
+    }
+
+    /// Returns the date at the given instant in the event's local timezone.
+    ///
+    /// This uses the event's timezone if it has one. Otherwise, for events with a time it uses the
+    /// event's UTC offset, and for date-only events it uses the default timezone for the event's
+    /// country and state if there is one, or else UTC.
+    pub fn local_date(&self, now: DateTime<Utc>) -> NaiveDate {
+        if let Some(timezone) = self.timezone {
+            return now.with_timezone(&timezone).date_naive();
+        }
+        match self.time {
+            EventTime::DateTime { start, end: _ } => now.with_timezone(start.offset()).date_naive(),
+            EventTime::DateOnly { .. } => {
+                match default_timezone_for(&self.country, self.state.as_deref()) {
+                    Some(timezone) => now.with_timezone(&timezone).date_naive(),
+                    None => now.date_naive(),
+                }
+            }
+        }
+    }
+
+    /// Returns the latitude and longitude of the venue, if both are known.
+    pub fn coordinates(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+
+    /// Returns the great-circle distance in kilometres from the given latitude and longitude to the
+    /// venue, if the venue's coordinates are known.
+    pub fn distance_km(&self, (latitude, longitude): (f64, f64)) -> Option<f64> {
+        let (venue_latitude, venue_longitude) = self.coordinates()?;
+        // Haversine formula.
+        let (phi_1, phi_2) = (latitude.to_radians(), venue_latitude.to_radians());
+        let delta_phi = phi_2 - phi_1;
+        let delta_lambda = (venue_longitude - longitude).to_radians();
+        let a = (delta_phi / 2.0).sin().powi(2)
+            + phi_1.cos() * phi_2.cos() * (delta_lambda / 2.0).sin().powi(2);
+        Some(2.0 * EARTH_RADIUS_KM * a.sqrt().asin())
+    }
+
     /// Get the event's first non-Facebook non-FBB link.
     pub fn main_link(&self) -> Option<&String> {
         self.links.iter().find(|link| {
@@ -397,6 +690,42 @@ impl Event {
         }
     }
 
+    /// Formats the event start date/time, and end date/time if it is different, including the month
+    /// and year.
+    pub fn long_time(&self) -> String {
+        match self.time {
+            EventTime::DateOnly {
+                start_date,
+                end_date,
+            } => {
+                if !self.multiday() {
+                    start_date.format("%a %-d %B %Y").to_string()
+                } else {
+                    format!(
+                        "{}–{}",
+                        start_date.format("%a %-d %B %Y"),
+                        end_date.format("%a %-d %B %Y")
+                    )
+                }
+            }
+            EventTime::DateTime { start, end } => {
+                if !self.multiday() {
+                    format!(
+                        "{}–{}",
+                        start.format("%a %-d %B %Y %-l:%M %P"),
+                        end.format("%-l:%M %P")
+                    )
+                } else {
+                    format!(
+                        "{}–{}",
+                        start.format("%a %-d %B %Y %-l:%M %P"),
+                        end.format("%a %-d %B %Y %-l:%M %P")
+                    )
+                }
+            }
+        }
+    }
+
     /// Formats the event start time, and end date/time if it is different, assuming that the start
     /// date is already known.
     pub fn time_no_date(&self) -> String {
@@ -460,9 +789,22 @@ impl Event {
     pub fn hash_string(&self) -> String {
         hex::encode(Sha1::digest(serde_json::to_string(self).unwrap()))
     }
+
+    /// Returns the event's persistent ID if it has one, or else its hash.
+    ///
+    /// This should be preferred over `hash_string` for links, as it doesn't change when the event
+    /// is edited.
+    pub fn id_or_hash(&self) -> String {
+        self.id.clone().unwrap_or_else(|| self.hash_string())
+    }
+
+    /// Generates a new random ID to use for an event.
+    pub fn new_id() -> String {
+        Uuid::new_v4().to_string()
+    }
 }
 
-fn merge_strings(a: &Option<String>, b: &Option<String>) -> Option<String> {
+fn merge_options<T: Clone + PartialEq>(a: &Option<T>, b: &Option<T>) -> Option<T> {
     match (a, b) {
         (None, None) => None,
         (Some(o), None) | (None, Some(o)) => Some(o.clone()),
@@ -470,7 +812,7 @@ fn merge_strings(a: &Option<String>, b: &Option<String>) -> Option<String> {
             if a == b {
                 Some(a.clone())
             } else {
-                // Can't merge different strings.
+                // Can't merge different values.
                 None
             }
         }
@@ -483,6 +825,25 @@ pub struct Link {
     pub url: String,
 }
 
+/// Returns a valid event for tests to build on, with a name, date, location and style but nothing
+/// else.
+#[cfg(test)]
+pub fn test_event() -> Event {
+    let date = NaiveDate::from_ymd_opt(2020, 1, 2).unwrap();
+    Event {
+        name: "Test event".to_string(),
+        time: EventTime::DateOnly {
+            start_date: date,
+            end_date: date,
+        },
+        country: "Country".to_string(),
+        city: "City".to_string(),
+        styles: vec![DanceStyle::Balfolk],
+        social: true,
+        ..Default::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -493,6 +854,7 @@ mod tests {
         // An event which starts in the evening and finishes a bit after midnight shouldn't count as
         // a multi-day event.
         let mut event = Event {
+            id: None,
             name: "Test event".to_string(),
             details: None,
             links: vec![],
@@ -508,17 +870,35 @@ mod tests {
                     .single()
                     .unwrap(),
             },
+            timezone: None,
             country: "Country".to_string(),
             state: None,
             city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![],
             workshop: false,
             social: true,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         assert!(!event.multiday());
@@ -569,6 +949,71 @@ mod tests {
         assert!(event.multiday());
     }
 
+    #[test]
+    fn long_time() {
+        let mut event = Event {
+            id: None,
+            name: "Test event".to_string(),
+            details: None,
+            links: vec![],
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            },
+            timezone: None,
+            country: "Country".to_string(),
+            state: None,
+            city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![],
+            workshop: false,
+            social: true,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        assert_eq!(event.long_time(), "Sat 31 January 2026");
+
+        event.time = EventTime::DateOnly {
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+        };
+        assert_eq!(event.long_time(), "Sat 31 January 2026–Sun 1 February 2026");
+
+        event.time = EventTime::DateTime {
+            start: FixedOffset::east_opt(3600)
+                .unwrap()
+                .with_ymd_and_hms(2026, 1, 31, 19, 30, 0)
+                .single()
+                .unwrap(),
+            end: FixedOffset::east_opt(3600)
+                .unwrap()
+                .with_ymd_and_hms(2026, 1, 31, 23, 0, 0)
+                .single()
+                .unwrap(),
+        };
+        assert_eq!(event.long_time(), "Sat 31 January 2026 7:30 pm–11:00 pm");
+    }
+
     #[test]
     fn serialize_event_time() {
         assert_eq!(
@@ -593,6 +1038,7 @@ end: "2024-01-02T13:00:00+00:00"
     #[test]
     fn hash() {
         let event = Event {
+            id: None,
             name: "Test event".to_string(),
             details: None,
             links: vec![],
@@ -608,17 +1054,35 @@ end: "2024-01-02T13:00:00+00:00"
                     .single()
                     .unwrap(),
             },
+            timezone: None,
             country: "Country".to_string(),
             state: None,
             city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![],
             workshop: false,
             social: true,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         assert_eq!(
@@ -626,4 +1090,391 @@ end: "2024-01-02T13:00:00+00:00"
             "5b002f08ede9bae9d4186b4ea1d14847b4d1f1ff"
         );
     }
+
+    #[test]
+    fn merge_venue() {
+        let event = Event {
+            id: None,
+            name: "Test event".to_string(),
+            details: None,
+            links: vec![],
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2020, 1, 2).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2020, 1, 2).unwrap(),
+            },
+            timezone: None,
+            country: "Country".to_string(),
+            state: None,
+            city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![DanceStyle::Balfolk],
+            workshop: false,
+            social: true,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        let with_venue = Event {
+            venue: Some("Village hall".to_string()),
+            address: Some("1 High Street".to_string()),
+            latitude: Some(51.0),
+            longitude: Some(-1.5),
+            ..event.clone()
+        };
+
+        let merged = event.merge(&with_venue).unwrap();
+        assert_eq!(merged.venue.as_deref(), Some("Village hall"));
+        assert_eq!(merged.address.as_deref(), Some("1 High Street"));
+        assert_eq!(merged.coordinates(), Some((51.0, -1.5)));
+
+        // Different coordinates can't be merged, so they are dropped.
+        let elsewhere = Event {
+            latitude: Some(52.0),
+            ..with_venue.clone()
+        };
+        let merged = with_venue.merge(&elsewhere).unwrap();
+        assert_eq!(merged.venue.as_deref(), Some("Village hall"));
+        assert_eq!(merged.coordinates(), None);
+    }
+
+    #[test]
+    fn merge_teachers() {
+        let event: Event = serde_yaml::from_str(
+            r#"
+name: Polska workshop
+start_date: 2020-01-02
+end_date: 2020-01-02
+country: Sweden
+city: Uppsala
+styles: [scandi]
+workshop: true
+teachers: [Anna, Bo]
+"#,
+        )
+        .unwrap();
+        let other = Event {
+            teachers: vec!["Cecilia".to_string(), "Anna".to_string()],
+            ..event.clone()
+        };
+
+        let merged = event.merge(&other).unwrap();
+        assert_eq!(merged.teachers, vec!["Anna", "Bo", "Cecilia"]);
+    }
+
+    #[test]
+    fn legacy_cancelled() {
+        let event: Event = serde_yaml::from_str(
+            r#"
+name: Ceilidh
+start_date: 2020-01-02
+end_date: 2020-01-02
+country: UK
+city: London
+styles: [e-ceilidh]
+social: true
+cancelled: true
+"#,
+        )
+        .unwrap();
+        assert_eq!(event.status, Status::Cancelled);
+
+        let yaml = serde_yaml::to_string(&event).unwrap();
+        assert!(yaml.contains("status: cancelled\n"));
+        assert!(!yaml.contains("cancelled: true"));
+
+        let event: Event = serde_yaml::from_str(&yaml.replace("cancelled", "sold_out")).unwrap();
+        assert_eq!(event.status, Status::SoldOut);
+        assert_eq!(event.validate(), Vec::<&str>::new());
+
+        let event = Event {
+            rescheduled_to: Some("new-id".to_string()),
+            ..event
+        };
+        assert_eq!(
+            event.validate(),
+            vec!["Only rescheduled events may give a new event."]
+        );
+    }
+
+    #[test]
+    fn validate_online() {
+        let mut event: Event = serde_yaml::from_str(
+            r#"
+name: Online ECD
+start: 2020-01-02T19:00:00-05:00
+end: 2020-01-02T21:00:00-05:00
+styles: [ecd]
+social: true
+attendance: online
+stream_url: https://example.com/zoom
+"#,
+        )
+        .unwrap();
+        assert_eq!(event.validate(), Vec::<&str>::new());
+
+        event.attendance = AttendanceMode::Hybrid;
+        assert_eq!(
+            event.validate(),
+            vec!["Must specify a country.", "Must specify a city."]
+        );
+
+        event.stream_url = Some("javascript:alert(1)".to_string());
+        assert_eq!(
+            event.validate(),
+            vec![
+                "Must specify a country.",
+                "Must specify a city.",
+                "Stream URL must start with http:// or https://."
+            ]
+        );
+
+        event.stream_url = Some("https://example.com/zoom".to_string());
+        event.attendance = AttendanceMode::InPerson;
+        event.country = "USA".to_string();
+        event.city = "Boston".to_string();
+        assert_eq!(
+            event.validate(),
+            vec!["Only online or hybrid events may have a stream URL."]
+        );
+    }
+
+    #[test]
+    fn apply_sessions() {
+        let mut event: Event = serde_yaml::from_str(
+            r#"
+name: Balfolk evening
+start: 2026-10-06T19:00:00+02:00
+end: 2026-10-06T23:30:00+02:00
+country: Belgium
+city: Gent
+styles: [balfolk]
+bands: [Naragonia]
+sessions:
+  - kind: workshop
+    name: Mazurka for beginners
+    start: 2026-10-06T19:00:00+02:00
+    end: 2026-10-06T20:30:00+02:00
+    teachers: [Anne]
+  - kind: social
+    start: 2026-10-06T20:30:00+02:00
+    end: 2026-10-06T23:30:00+02:00
+    bands: [Naragonia, Duo Absynthe]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            event.validate(),
+            vec!["Must have at least a workshop or a social."]
+        );
+
+        event.apply_sessions();
+        assert!(event.workshop);
+        assert!(event.social);
+        assert_eq!(event.bands, vec!["Naragonia", "Duo Absynthe"]);
+        assert_eq!(event.teachers, vec!["Anne"]);
+        assert_eq!(event.validate(), Vec::<&str>::new());
+        assert_eq!(
+            event.sessions[0].summary(),
+            "Tue 6 7:00 pm–8:30 pm workshop: Mazurka for beginners, teachers: Anne"
+        );
+
+        event.sessions[1].time = EventTime::DateOnly {
+            start_date: NaiveDate::from_ymd_opt(2026, 10, 7).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 10, 7).unwrap(),
+        };
+        assert_eq!(
+            event.validate(),
+            vec!["Sessions must be within the dates of the event."]
+        );
+    }
+
+    #[test]
+    fn validate_coordinates() {
+        let mut event = Event {
+            id: None,
+            name: "Test event".to_string(),
+            details: None,
+            links: vec![],
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2020, 1, 2).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2020, 1, 2).unwrap(),
+            },
+            timezone: None,
+            country: "Country".to_string(),
+            state: None,
+            city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: Some(51.0),
+            longitude: Some(-1.5),
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![DanceStyle::Balfolk],
+            workshop: false,
+            social: true,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        assert_eq!(event.validate(), Vec::<&str>::new());
+
+        event.longitude = None;
+        assert_eq!(
+            event.validate(),
+            vec!["Must specify both latitude and longitude, or neither."]
+        );
+
+        event.latitude = Some(f64::NAN);
+        event.longitude = Some(200.0);
+        assert_eq!(
+            event.validate(),
+            vec![
+                "Latitude must be between -90 and 90 degrees.",
+                "Longitude must be between -180 and 180 degrees."
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_timezone() {
+        let mut event = Event {
+            id: None,
+            name: "Test event".to_string(),
+            details: None,
+            links: vec![],
+            time: EventTime::DateTime {
+                start: FixedOffset::east_opt(3600)
+                    .unwrap()
+                    .with_ymd_and_hms(2025, 3, 29, 19, 0, 0)
+                    .single()
+                    .unwrap(),
+                end: FixedOffset::east_opt(7200)
+                    .unwrap()
+                    .with_ymd_and_hms(2025, 3, 30, 16, 0, 0)
+                    .single()
+                    .unwrap(),
+            },
+            timezone: Some(Tz::Europe__Berlin),
+            country: "Germany".to_string(),
+            state: None,
+            city: "Berlin".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![DanceStyle::Balfolk],
+            workshop: false,
+            social: true,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        assert_eq!(event.validate(), Vec::<&str>::new());
+
+        event.timezone = Some(Tz::Europe__London);
+        assert_eq!(
+            event.validate(),
+            vec!["Start and end offsets must match the timezone."]
+        );
+
+        event.timezone = None;
+        assert_eq!(event.validate(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn local_date_timezone() {
+        let event = Event {
+            id: None,
+            name: "Test event".to_string(),
+            details: None,
+            links: vec![],
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            },
+            timezone: Some(Tz::America__Los_Angeles),
+            country: "Country".to_string(),
+            state: None,
+            city: "City".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![],
+            workshop: false,
+            social: true,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        assert_eq!(
+            event.local_date(Utc.with_ymd_and_hms(2025, 3, 2, 5, 0, 0).unwrap()),
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+        );
+    }
 }
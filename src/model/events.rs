@@ -12,7 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{dancestyle::DanceStyle, event::Event, filters::Filters};
+use super::{
+    dancestyle::DanceStyle,
+    event::Event,
+    filters::{Filters, SortOrder},
+    performers::{Bands, Callers, Performer},
+    recurrence::{RecurrenceWindow, expand},
+    registries::{Registries, is_registry_file},
+    series::{Series, collapse},
+    status::Status,
+    venues::Venues,
+};
 use chrono::Utc;
 use eyre::{Report, WrapErr, bail};
 use log::trace;
@@ -25,7 +35,7 @@ use std::{
     path::Path,
 };
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Events {
     pub events: Vec<Event>,
@@ -38,29 +48,63 @@ impl Events {
         }
     }
 
-    /// Load events from the given file, directory or URL.
+    /// Load events from the given file, directory or URL, expanding recurring events within the
+    /// default window.
     pub async fn load_events(path_or_url: &str) -> Result<Self, Report> {
+        Self::load_events_with_window(path_or_url, RecurrenceWindow::default()).await
+    }
+
+    /// Load events from the given file, directory or URL, expanding recurring events within the
+    /// given window.
+    pub async fn load_events_with_window(
+        path_or_url: &str,
+        window: RecurrenceWindow,
+    ) -> Result<Self, Report> {
+        Self::load_events_with_mode(path_or_url, LoadMode::Serve(window)).await
+    }
+
+    /// Load and validate events from the given file, directory or URL, but keep them as they are
+    /// stored, so that they can be written out again.
+    pub async fn load_stored_events(path_or_url: &str) -> Result<Self, Report> {
+        Self::load_events_with_mode(path_or_url, LoadMode::Stored).await
+    }
+
+    async fn load_events_with_mode(path_or_url: &str, mode: LoadMode) -> Result<Self, Report> {
         if path_or_url.starts_with("http://") || path_or_url.starts_with("https://") {
-            Self::load_url(path_or_url).await
+            Self::load_url(path_or_url, mode).await
         } else {
             let path = Path::new(path_or_url);
             if path.is_dir() {
-                Self::load_directory(path)
+                Self::load_directory(path, mode)
             } else {
-                Self::load_file(path)
+                Self::load_file(path, mode)
             }
         }
     }
 
     /// Load events from all YAML files in the given directory and its subdirectories.
-    pub fn load_directory(directory: &Path) -> Result<Self, Report> {
+    ///
+    /// Events may refer to venues and organisations in registry files in the same directory or any
+    /// of its parents, up to the given directory.
+    pub fn load_directory(directory: &Path, mode: LoadMode) -> Result<Self, Report> {
+        Self::load_directory_with_registries(directory, &Registries::default(), mode)
+    }
+
+    fn load_directory_with_registries(
+        directory: &Path,
+        parent_registries: &Registries,
+        mode: LoadMode,
+    ) -> Result<Self, Report> {
+        let registries = &parent_registries.extended_for_directory(directory)?;
         let mut events = vec![];
         for entry in read_dir(directory)? {
             let filename = entry?.path();
             let file_events = if filename.is_dir() {
-                Self::load_directory(&filename)?
+                Self::load_directory_with_registries(&filename, registries, mode)?
+            } else if is_registry_file(&filename) {
+                continue;
             } else if filename.extension() == Some(OsStr::new("yaml")) {
-                Self::load_file(&filename)?
+                Self::load_file_with_registries(&filename, registries, mode)?
             } else {
                 trace!("Not reading events from {filename:?}");
                 continue;
@@ -71,13 +115,30 @@ impl Events {
     }
 
     /// Load and validate events from the given YAML file.
-    pub fn load_file(filename: &Path) -> Result<Self, Report> {
+    ///
+    /// Events may refer to venues and organisations in registry files in the same directory or any
+    /// of its parents, up to the closest one named `events`.
+    pub fn load_file(filename: &Path, mode: LoadMode) -> Result<Self, Report> {
+        let registries = Registries::load_for_file(filename)?;
+        Self::load_file_with_registries(filename, &registries, mode)
+    }
+
+    fn load_file_with_registries(
+        filename: &Path,
+        registries: &Registries,
+        mode: LoadMode,
+    ) -> Result<Self, Report> {
         trace!("Reading events from {filename:?}");
         let contents =
             read_to_string(filename).wrap_err_with(|| format!("Reading {filename:?}"))?;
-        let mut events =
-            Self::load_str(&contents).wrap_err_with(|| format!("Reading {filename:?}"))?;
+        let stored = Self::load_str(&contents).wrap_err_with(|| format!("Reading {filename:?}"))?;
+        let mut events = stored
+            .prepared(registries)
+            .wrap_err_with(|| format!("Reading {filename:?}"))?;
         events.validate()?;
+        if mode == LoadMode::Stored {
+            events = stored;
+        }
 
         // Fill in the source with the filename, if the event doesn't already have one.
         if let Some(source) = filename.to_str() {
@@ -87,6 +148,11 @@ impl Events {
                 }
             }
         }
+        if let LoadMode::Serve(window) = mode {
+            events
+                .expand_recurrences(window)
+                .wrap_err_with(|| format!("Reading {filename:?}"))?;
+        }
 
         Ok(events)
     }
@@ -99,11 +165,19 @@ impl Events {
     }
 
     /// Loads events from the given YAML URL and validates them.
-    pub async fn load_url(url: &str) -> Result<Self, Report> {
+    pub async fn load_url(url: &str, mode: LoadMode) -> Result<Self, Report> {
         let contents = reqwest::get(url).await?.text().await?;
-        let events = Self::load_str(&contents).wrap_err_with(|| format!("Reading {url}"))?;
+        let stored = Self::load_str(&contents).wrap_err_with(|| format!("Reading {url}"))?;
+        // There are no registry files alongside a URL, so any venue references are errors.
+        let mut events = stored.prepared(&Registries::default())?;
         events.validate()?;
-        Ok(events)
+        match mode {
+            LoadMode::Stored => Ok(stored),
+            LoadMode::Serve(window) => {
+                events.expand_recurrences(window)?;
+                Ok(events)
+            }
+        }
     }
 
     /// Loads events from the given YAML string.
@@ -125,6 +199,86 @@ impl Events {
         Ok(())
     }
 
+    /// Returns a copy of the events with details filled in from the given registries and from their
+    /// sessions, as they are shown on the website.
+    ///
+    /// Recurring events are not expanded.
+    pub fn prepared(&self, registries: &Registries) -> Result<Self, Report> {
+        let mut events = self.clone();
+        events.resolve_venues(&registries.venues)?;
+        events.normalise_names(registries);
+        events.apply_sessions();
+        Ok(events)
+    }
+
+    /// Replaces each recurring event with an event for each of its instances within the given
+    /// window around the current time.
+    pub fn expand_recurrences(&mut self, window: RecurrenceWindow) -> Result<(), Report> {
+        let now = Utc::now();
+        let mut events = Vec::with_capacity(self.events.len());
+        for event in self.events.drain(..) {
+            events.extend(expand(event, window, now)?);
+        }
+        self.events = events;
+        Ok(())
+    }
+
+    /// Fills in the details of each event from the venue it refers to, if any.
+    pub fn resolve_venues(&mut self, venues: &Venues) -> Result<(), Report> {
+        for event in &mut self.events {
+            if let Some(venue_id) = &event.venue_id {
+                let Some(venue) = venues.get(venue_id) else {
+                    bail!(
+                        "Event '{}' refers to unknown venue '{venue_id}'",
+                        event.name
+                    );
+                };
+                venue.apply_to(event);
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets whether each event with sessions has a workshop and a social, and adds the performers
+    /// of its sessions, as described by `Event::apply_sessions`.
+    pub fn apply_sessions(&mut self) {
+        for event in &mut self.events {
+            event.apply_sessions();
+        }
+    }
+
+    /// Replaces any organisation, band or caller names which are aliases with the canonical name
+    /// from the registry, including those in sessions.
+    pub fn normalise_names(&mut self, registries: &Registries) {
+        for event in &mut self.events {
+            if let Some(organisation) = &event.organisation
+                && let Some(details) = registries.organisations.get(organisation)
+            {
+                event.organisation = Some(details.name.clone());
+            }
+            for band in event.bands.iter_mut().chain(
+                event
+                    .sessions
+                    .iter_mut()
+                    .flat_map(|session| &mut session.bands),
+            ) {
+                if let Some(performer) = registries.bands.get(band) {
+                    band.clone_from(&performer.name);
+                }
+            }
+            for caller in event.callers.iter_mut().chain(
+                event
+                    .sessions
+                    .iter_mut()
+                    .flat_map(|session| &mut session.callers),
+            ) {
+                if let Some(performer) = registries.callers.get(caller) {
+                    caller.clone_from(&performer.name);
+                }
+            }
+        }
+    }
+
     /// Converts the events to a YAML string.
     pub fn to_yaml_string(&self) -> Result<String, Report> {
         let yaml = serde_yaml::to_string(self)?;
@@ -140,7 +294,8 @@ impl Events {
         self.events.sort_by_key(Event::date_location_sort_key);
     }
 
-    /// Get all events matching the given filters, sorted by start time then location.
+    /// Get all events matching the given filters, sorted by start time then location, or by
+    /// distance if the filters ask for that.
     pub fn sorted_matching(self, filters: &Filters) -> Self {
         let now = Utc::now();
         let mut events = Self {
@@ -151,37 +306,126 @@ impl Events {
                 .collect(),
         };
         events.sort();
+        if let (SortOrder::Distance, Some(near)) = (filters.sort, filters.near) {
+            // This is a stable sort, so events at the same distance stay in date order.
+            events.events.sort_by(|a, b| {
+                let distance_a = a.distance_km(near).unwrap_or(f64::INFINITY);
+                let distance_b = b.distance_km(near).unwrap_or(f64::INFINITY);
+                distance_a.total_cmp(&distance_b)
+            });
+        }
         events.events.truncate(filters.limit.unwrap_or(usize::MAX));
         events
     }
 
-    /// Returns the event with the given SHA-1 hash, if any.
+    /// Detects events which appear to be regular instances of the same series, and replaces each
+    /// series with its first instance paired with the whole series. Other events are paired with
+    /// `None`.
+    pub fn collapse_series(self) -> Vec<(Event, Option<Series>)> {
+        collapse(self.events)
+    }
+
+    /// Returns the event with the given SHA-1 hash or persistent ID, if any.
     pub fn with_hash(&self, hash: &str) -> Option<&Event> {
-        self.events.iter().find(|event| event.hash_string() == hash)
+        self.events
+            .iter()
+            .find(|event| event.id.as_deref() == Some(hash) || event.hash_string() == hash)
     }
 
-    /// Gets all bands who play for at least one event, in alphabetical order.
-    pub fn bands(&self) -> Vec<Band> {
-        let mut bands: Vec<Band> =
-            count_duplicates(self.events.iter().flat_map(|event| event.bands.clone()))
-                .into_iter()
-                .map(|(name, event_count)| Band { name, event_count })
-                .collect();
+    /// Generates a new ID for each event which doesn't already have one.
+    pub fn assign_ids(&mut self) {
+        for event in &mut self.events {
+            if event.id.is_none() {
+                event.id = Some(Event::new_id());
+            }
+        }
+    }
+
+    /// Replaces the stored event which is shown on the website as `original` with `new`, converted
+    /// back to the form in which it should be stored.
+    ///
+    /// The events are as stored in a file, while `original` and `new` have details filled in from
+    /// the given registries as by `prepared`. Returns an error if none of the events is shown as
+    /// `original`.
+    pub fn replace_prepared(
+        &mut self,
+        original: &Event,
+        new: Event,
+        registries: &Registries,
+    ) -> Result<(), Report> {
+        let prepared = self.prepared(registries)?;
+        let Some(index) = prepared.events.iter().position(|event| match &original.id {
+            Some(id) => event.id.as_ref() == Some(id),
+            None => event == original,
+        }) else {
+            bail!("Couldn't find event '{}' to replace", original.name);
+        };
+        self.events[index] = unprepare(
+            new,
+            &self.events[index],
+            &prepared.events[index],
+            registries,
+        );
+        Ok(())
+    }
+
+    /// Gets all bands who play for at least one event or are in the given registry, in
+    /// alphabetical order.
+    pub fn bands(&self, known: &Bands) -> Vec<Band> {
+        let mut bands: Vec<Band> = count_performers(
+            self.events.iter().map(|event| (&event.bands, event)),
+            &known.bands,
+        )
+        .into_iter()
+        .map(|(name, (upcoming_count, past_count), performer)| Band {
+            name,
+            upcoming_count,
+            past_count,
+            country: performer.and_then(|performer| performer.country.clone()),
+            website: performer.and_then(|performer| performer.website.clone()),
+        })
+        .collect();
         bands.sort();
         bands
     }
 
-    /// Gets all callers who call for at least one event, in alphabetical order.
-    pub fn callers(&self) -> Vec<Caller> {
-        let mut callers: Vec<Caller> =
-            count_duplicates(self.events.iter().flat_map(|event| event.callers.clone()))
-                .into_iter()
-                .map(|(name, event_count)| Caller { name, event_count })
-                .collect();
+    /// Gets all callers who call for at least one event or are in the given registry, in
+    /// alphabetical order.
+    pub fn callers(&self, known: &Callers) -> Vec<Caller> {
+        let mut callers: Vec<Caller> = count_performers(
+            self.events.iter().map(|event| (&event.callers, event)),
+            &known.callers,
+        )
+        .into_iter()
+        .map(|(name, (upcoming_count, past_count), performer)| Caller {
+            name,
+            upcoming_count,
+            past_count,
+            country: performer.and_then(|performer| performer.country.clone()),
+            website: performer.and_then(|performer| performer.website.clone()),
+        })
+        .collect();
         callers.sort();
         callers
     }
 
+    /// Gets all teachers who teach at least one event, in alphabetical order.
+    pub fn teachers(&self) -> Vec<Teacher> {
+        let mut teachers: Vec<Teacher> = count_performers(
+            self.events.iter().map(|event| (&event.teachers, event)),
+            &[],
+        )
+        .into_iter()
+        .map(|(name, (upcoming_count, past_count), _)| Teacher {
+            name,
+            upcoming_count,
+            past_count,
+        })
+        .collect();
+        teachers.sort();
+        teachers
+    }
+
     /// Gets all dance organisations, in alphabetical order.
     pub fn organisations(&self) -> Vec<Organisation> {
         let mut organisations: Vec<Organisation> = count_duplicates(
@@ -198,11 +442,13 @@ impl Events {
 
     /// Gets all cities which have dance events matching the given filters, grouped by country and
     /// possibly state, in alphabetical order.
+    ///
+    /// Online-only events are skipped, as they don't take place in any city.
     pub fn countries(&self, filters: &Filters) -> Vec<Country> {
         let now = Utc::now();
         let mut countries = HashMap::new();
         for event in &self.events {
-            if filters.matches(event, now) {
+            if event.attendance.in_person() && filters.matches(event, now) {
                 let (cities, states) = countries
                     .entry(event.country.to_owned())
                     .or_insert_with(|| (Vec::new(), HashMap::<String, Vec<String>>::new()));
@@ -289,6 +535,17 @@ impl Events {
     }
 }
 
+/// How events are prepared after they are read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LoadMode {
+    /// Keep events as they are stored, so that they can be written out again. They are still
+    /// validated as they would be shown.
+    Stored,
+    /// Fill in details from registries and sessions, normalise names, and expand recurring events
+    /// within the given window, ready to be shown on the website.
+    Serve(RecurrenceWindow),
+}
+
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Country {
     pub name: String,
@@ -305,13 +562,32 @@ pub struct State {
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Band {
     pub name: String,
-    pub event_count: usize,
+    /// The number of events which haven't yet finished.
+    pub upcoming_count: usize,
+    /// The number of events which have finished.
+    pub past_count: usize,
+    pub country: Option<String>,
+    pub website: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Caller {
     pub name: String,
-    pub event_count: usize,
+    /// The number of events which haven't yet finished.
+    pub upcoming_count: usize,
+    /// The number of events which have finished.
+    pub past_count: usize,
+    pub country: Option<String>,
+    pub website: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Teacher {
+    pub name: String,
+    /// The number of events which haven't yet finished.
+    pub upcoming_count: usize,
+    /// The number of events which have finished.
+    pub past_count: usize,
 }
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -320,6 +596,103 @@ pub struct Organisation {
     pub event_count: usize,
 }
 
+/// Converts an event as shown on the website back to the form in which it is stored, undoing what
+/// `Events::prepared` filled in from the given registries.
+///
+/// `stored` is the event which is being replaced, and `shown` is how it was shown on the website.
+fn unprepare(mut event: Event, stored: &Event, shown: &Event, registries: &Registries) -> Event {
+    if let Some(venue_id) = &event.venue_id
+        && let Some(venue) = registries.venues.get(venue_id)
+    {
+        venue.remove_from(&mut event);
+    }
+    // Keep the name the organisation was stored under, if it wasn't changed.
+    if event.organisation == shown.organisation {
+        event.organisation.clone_from(&stored.organisation);
+    }
+    // Don't store the performers and kinds of event which were filled in from sessions.
+    if event.sessions == shown.sessions && !stored.sessions.is_empty() {
+        remove_added(&mut event.bands, &shown.bands[stored.bands.len()..]);
+        remove_added(&mut event.callers, &shown.callers[stored.callers.len()..]);
+        remove_added(
+            &mut event.teachers,
+            &shown.teachers[stored.teachers.len()..],
+        );
+        event.workshop = stored.workshop;
+        event.social = stored.social;
+    }
+    // Keep the names bands and callers were stored under too, and sessions which can't be edited
+    // on the website.
+    restore_aliases(&mut event.bands, &stored.bands, |name| {
+        registries.bands.get(name)
+    });
+    restore_aliases(&mut event.callers, &stored.callers, |name| {
+        registries.callers.get(name)
+    });
+    if event.sessions == shown.sessions {
+        event.sessions.clone_from(&stored.sessions);
+    }
+    event
+}
+
+/// Removes the names which were added to the event from its sessions.
+fn remove_added(names: &mut Vec<String>, added: &[String]) {
+    names.retain(|name| !added.contains(name));
+}
+
+/// Replaces each name which is the canonical name of a performer stored under one of the `stored`
+/// names with that stored name.
+fn restore_aliases<'a>(
+    names: &mut [String],
+    stored: &[String],
+    get: impl Fn(&str) -> Option<&'a Performer>,
+) {
+    for name in names {
+        if let Some(stored_name) = stored.iter().find(|stored_name| {
+            get(stored_name).map_or(stored_name.as_str(), |performer| &performer.name) == name
+        }) {
+            name.clone_from(stored_name);
+        }
+    }
+}
+
+/// Counts the number of upcoming and past events for each performer name in the iterator,
+/// including known performers with no events, along with the registry entry for each if there is
+/// one.
+fn count_performers<'a>(
+    appearances: impl Iterator<Item = (&'a Vec<String>, &'a Event)>,
+    known: &[Performer],
+) -> Vec<(String, (usize, usize), Option<&Performer>)> {
+    let now = Utc::now();
+    let upcoming = Filters::default();
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    for (names, event) in appearances {
+        // Cancelled events won't happen, so count them neither as upcoming nor as past.
+        if event.status == Status::Cancelled {
+            continue;
+        }
+        let is_upcoming = upcoming.matches(event, now);
+        for name in names {
+            let (upcoming_count, past_count) = counts.entry(name.clone()).or_default();
+            if is_upcoming {
+                *upcoming_count += 1;
+            } else {
+                *past_count += 1;
+            }
+        }
+    }
+    for performer in known {
+        counts.entry(performer.name.clone()).or_default();
+    }
+    counts
+        .into_iter()
+        .map(|(name, count)| {
+            let performer = known.iter().find(|performer| performer.name == name);
+            (name, count, performer)
+        })
+        .collect()
+}
+
 /// Counts the number of occurrences of duplicate items in the iterator.
 fn count_duplicates(elements: impl Iterator<Item = String>) -> HashMap<String, usize> {
     let mut counts = HashMap::new();
@@ -333,12 +706,16 @@ fn count_duplicates(elements: impl Iterator<Item = String>) -> HashMap<String, u
 mod tests {
     use super::*;
 
-    use crate::model::{dancestyle::DanceStyle, event::EventTime, filters::DateFilter};
+    use crate::model::{
+        attendance::AttendanceMode, dancestyle::DanceStyle, event::EventTime, filters::DateFilter,
+        organisations::Organisations, status::Status,
+    };
     use chrono::NaiveDate;
 
     #[test]
     fn countries() {
         let london_event_1 = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -346,20 +723,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "UK".to_string(),
             state: None,
             city: "London".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let london_event_2 = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -367,20 +763,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "UK".to_string(),
             state: None,
             city: "London".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let oxford_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -388,20 +803,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "UK".to_string(),
             state: None,
             city: "Oxford".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let amsterdam_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -409,20 +843,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Netherlands".to_string(),
             state: None,
             city: "Amsterdam".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let berkeley_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -430,17 +883,35 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "USA".to_string(),
             state: Some("CA".to_string()),
             city: "Berkeley".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::Contra],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let events = Events {
@@ -480,6 +951,7 @@ mod tests {
     #[test]
     fn states() {
         let oxford_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -487,20 +959,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "UK".to_string(),
             state: None,
             city: "Oxford".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let berkeley_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -508,20 +999,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "USA".to_string(),
             state: Some("CA".to_string()),
             city: "Berkeley".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::Contra],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let sf_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -529,20 +1039,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "USA".to_string(),
             state: Some("CA".to_string()),
             city: "San Francisco".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::Contra],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let boston_event = Event {
+            id: None,
             name: "Name".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
@@ -550,17 +1079,35 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "USA".to_string(),
             state: Some("MA".to_string()),
             city: "Boston".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::Contra],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let events = Events {
@@ -575,6 +1122,7 @@ mod tests {
     #[test]
     fn filter_past() {
         let past_event = Event {
+            id: None,
             name: "Past".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(1000, 1, 1).unwrap(),
@@ -582,20 +1130,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let future_event = Event {
+            id: None,
             name: "Future".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(3000, 1, 1).unwrap(),
@@ -603,17 +1170,35 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let events = Events {
@@ -649,6 +1234,7 @@ mod tests {
     #[test]
     fn limit() {
         let a = Event {
+            id: None,
             name: "A".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
@@ -656,20 +1242,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let b = Event {
+            id: None,
             name: "B".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2000, 2, 1).unwrap(),
@@ -677,20 +1282,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let c = Event {
+            id: None,
             name: "C".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2000, 2, 2).unwrap(),
@@ -698,20 +1322,39 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
         let d = Event {
+            id: None,
             name: "D".to_string(),
             time: EventTime::DateOnly {
                 start_date: NaiveDate::from_ymd_opt(2000, 2, 3).unwrap(),
@@ -719,17 +1362,35 @@ mod tests {
             },
             details: None,
             links: vec![],
+            timezone: None,
             country: "Test".to_string(),
             state: None,
             city: "Test".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
             styles: vec![DanceStyle::EnglishCountryDance],
             workshop: true,
             social: false,
             bands: vec![],
             callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
             price: None,
             organisation: None,
-            cancelled: false,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
             source: None,
         };
 
@@ -748,4 +1409,654 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn near() {
+        let make_event = |name: &str, coordinates: Option<(f64, f64)>| Event {
+            id: None,
+            name: name.to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+            },
+            details: None,
+            links: vec![],
+            timezone: None,
+            country: "Test".to_string(),
+            state: None,
+            city: name.to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: coordinates.map(|(latitude, _)| latitude),
+            longitude: coordinates.map(|(_, longitude)| longitude),
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![DanceStyle::Balfolk],
+            workshop: false,
+            social: true,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        let liege = make_event("Liège", Some((50.6326, 5.5797)));
+        let maastricht = make_event("Maastricht", Some((50.8514, 5.6910)));
+        let cologne = make_event("Cologne", Some((50.9375, 6.9603)));
+        let unknown = make_event("Unknown", None);
+        let events = Events {
+            events: vec![
+                liege.clone(),
+                maastricht.clone(),
+                cologne.clone(),
+                unknown.clone(),
+            ],
+        };
+        let aachen = (50.7753, 6.0839);
+
+        assert_eq!(
+            events.clone().sorted_matching(&Filters {
+                date: DateFilter::All,
+                near: Some(aachen),
+                ..Default::default()
+            }),
+            Events {
+                events: vec![liege.clone(), maastricht.clone()]
+            }
+        );
+        assert_eq!(
+            events.clone().sorted_matching(&Filters {
+                date: DateFilter::All,
+                near: Some(aachen),
+                radius_km: Some(100),
+                sort: SortOrder::Distance,
+                ..Default::default()
+            }),
+            Events {
+                events: vec![maastricht, liege, cologne]
+            }
+        );
+    }
+
+    #[test]
+    fn with_hash_or_id() {
+        let event = Event {
+            id: None,
+            name: "Name".to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+            },
+            details: None,
+            links: vec![],
+            timezone: None,
+            country: "UK".to_string(),
+            state: None,
+            city: "London".to_string(),
+            venue_id: None,
+            venue: None,
+            address: None,
+            latitude: None,
+            longitude: None,
+            attendance: AttendanceMode::InPerson,
+            stream_url: None,
+            styles: vec![DanceStyle::EnglishCountryDance],
+            workshop: true,
+            social: false,
+            bands: vec![],
+            callers: vec![],
+            teachers: vec![],
+            sessions: vec![],
+            level: None,
+            role_terms: None,
+            no_partner_needed: false,
+            family_friendly: false,
+            accessibility: vec![],
+            price: None,
+            organisation: None,
+            status: Status::Scheduled,
+            rescheduled_to: None,
+            recurrence: None,
+            series: None,
+            source: None,
+        };
+        let hash = event.hash_string();
+        let mut events = Events {
+            events: vec![event],
+        };
+        assert_eq!(events.with_hash(&hash), Some(&events.events[0]));
+        assert_eq!(events.events[0].id_or_hash(), hash);
+
+        events.assign_ids();
+        let id = events.events[0].id.clone().unwrap();
+        assert_eq!(events.events[0].id_or_hash(), id);
+
+        // Assigning again shouldn't change existing IDs.
+        events.assign_ids();
+        assert_eq!(events.events[0].id.as_ref(), Some(&id));
+
+        // Editing the event changes its hash, but it can still be found by ID.
+        events.events[0].name = "New name".to_string();
+        assert_eq!(events.with_hash(&hash), None);
+        assert_eq!(events.with_hash(&id), Some(&events.events[0]));
+        assert_eq!(
+            events.with_hash(&events.events[0].hash_string()),
+            Some(&events.events[0])
+        );
+    }
+
+    #[test]
+    fn resolve_venues() {
+        let venues = Venues::load_str(
+            r#"
+venues:
+  - id: shelter-hall
+    name: Shelter Hall
+    city: Dunedin
+    country: New Zealand
+"#,
+        )
+        .unwrap();
+        let mut events = Events::load_str(
+            r#"
+events:
+  - name: Barn dance
+    start_date: 2026-03-07
+    end_date: 2026-03-07
+    venue_id: shelter-hall
+    styles: [contra]
+    social: true
+"#,
+        )
+        .unwrap();
+        let original = events.clone();
+
+        events.resolve_venues(&venues).unwrap();
+        let event = &events.events[0];
+        assert_eq!(event.venue.as_deref(), Some("Shelter Hall"));
+        assert_eq!(event.city, "Dunedin");
+        assert_eq!(event.country, "New Zealand");
+        assert!(event.validate().is_empty());
+
+        venues
+            .get("shelter-hall")
+            .unwrap()
+            .remove_from(&mut events.events[0]);
+        assert_eq!(events, original);
+
+        // References to venues which don't exist are an error.
+        let mut events = original.clone();
+        assert!(events.resolve_venues(&Venues::default()).is_err());
+    }
+
+    #[test]
+    fn replace_prepared_venue() {
+        let registries = Registries {
+            venues: Venues::load_str(
+                r#"
+venues:
+  - id: shelter-hall
+    name: Shelter Hall
+    city: Dunedin
+    country: New Zealand
+"#,
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        let mut stored = Events::load_str(
+            r#"
+events:
+  - name: Barn dance
+    start_date: 2026-03-07
+    end_date: 2026-03-07
+    venue_id: shelter-hall
+    styles: [contra]
+    social: true
+  - name: Contra dance
+    start_date: 2026-03-14
+    end_date: 2026-03-14
+    venue_id: shelter-hall
+    styles: [contra]
+    social: true
+"#,
+        )
+        .unwrap();
+        let original = stored.prepared(&registries).unwrap().events[0].clone();
+        let mut new = original.clone();
+        new.name = "Ceilidh".to_string();
+        new.styles = vec![DanceStyle::EnglishCeilidh];
+
+        stored
+            .replace_prepared(&original, new, &registries)
+            .unwrap();
+        let event = &stored.events[0];
+        assert_eq!(event.name, "Ceilidh");
+        assert_eq!(event.venue_id.as_deref(), Some("shelter-hall"));
+        assert_eq!(event.venue, None);
+        assert_eq!(event.city, "");
+        assert_eq!(event.country, "");
+        assert_eq!(stored.events[1].name, "Contra dance");
+
+        // The original is no longer there to replace.
+        assert!(
+            stored
+                .replace_prepared(&original, original.clone(), &registries)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn replace_prepared_organisation_alias() {
+        let registries = Registries {
+            organisations: Organisations::load_str(
+                r#"
+organisations:
+  - name: Folkdance Dunedin
+    aliases: [FDD]
+"#,
+            )
+            .unwrap(),
+            ..Default::default()
+        };
+        let mut stored = Events::load_str(
+            r#"
+events:
+  - name: Barn dance
+    start_date: 2026-03-07
+    end_date: 2026-03-07
+    country: New Zealand
+    city: Dunedin
+    styles: [contra]
+    social: true
+    organisation: FDD
+"#,
+        )
+        .unwrap();
+        let original = stored.prepared(&registries).unwrap().events[0].clone();
+        assert_eq!(original.organisation.as_deref(), Some("Folkdance Dunedin"));
+
+        // An unchanged organisation keeps the alias it was stored under.
+        let mut new = original.clone();
+        new.name = "Ceilidh".to_string();
+        stored
+            .replace_prepared(&original, new.clone(), &registries)
+            .unwrap();
+        assert_eq!(stored.events[0].name, "Ceilidh");
+        assert_eq!(stored.events[0].organisation.as_deref(), Some("FDD"));
+
+        // A changed organisation is stored as entered.
+        let original = new;
+        let mut new = original.clone();
+        new.organisation = Some("Someone else".to_string());
+        stored
+            .replace_prepared(&original, new, &registries)
+            .unwrap();
+        assert_eq!(
+            stored.events[0].organisation.as_deref(),
+            Some("Someone else")
+        );
+    }
+
+    #[test]
+    fn replace_prepared_performer_aliases() {
+        let registries = Registries {
+            bands: Bands::new(vec![Performer {
+                name: "The Contra Band".to_string(),
+                aliases: vec!["Contra Band".to_string()],
+                country: None,
+                website: None,
+            }]),
+            ..Default::default()
+        };
+        let mut stored = Events::load_str(
+            r#"
+events:
+  - name: Barn dance
+    start_date: 2026-03-07
+    end_date: 2026-03-07
+    country: New Zealand
+    city: Dunedin
+    styles: [contra]
+    social: true
+    bands: [contra band, Someone else]
+"#,
+        )
+        .unwrap();
+        let original = stored.prepared(&registries).unwrap().events[0].clone();
+        assert_eq!(original.bands, vec!["The Contra Band", "Someone else"]);
+
+        let mut new = original.clone();
+        new.bands.push("Another band".to_string());
+        stored
+            .replace_prepared(&original, new, &registries)
+            .unwrap();
+        assert_eq!(
+            stored.events[0].bands,
+            vec!["contra band", "Someone else", "Another band"]
+        );
+    }
+
+    #[test]
+    fn replace_prepared_sessions() {
+        let registries = Registries::default();
+        let mut stored = Events::load_str(
+            r#"
+events:
+  - name: Balfolk evening
+    start: 2026-10-06T19:00:00+02:00
+    end: 2026-10-06T23:30:00+02:00
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    bands: [Naragonia]
+    sessions:
+      - kind: workshop
+        start: 2026-10-06T19:00:00+02:00
+        end: 2026-10-06T20:30:00+02:00
+        teachers: [Anne]
+      - kind: social
+        start: 2026-10-06T20:30:00+02:00
+        end: 2026-10-06T23:30:00+02:00
+        bands: [Naragonia, Duo Absynthe]
+"#,
+        )
+        .unwrap();
+        let original = stored.prepared(&registries).unwrap().events[0].clone();
+        assert_eq!(original.bands, vec!["Naragonia", "Duo Absynthe"]);
+        assert!(original.workshop);
+
+        let mut new = original.clone();
+        new.name = "Bal".to_string();
+        new.bands.push("Trio Dhoore".to_string());
+        let expected_sessions = stored.events[0].sessions.clone();
+        stored
+            .replace_prepared(&original, new, &registries)
+            .unwrap();
+        let event = &stored.events[0];
+        assert_eq!(event.name, "Bal");
+        assert_eq!(event.bands, vec!["Naragonia", "Trio Dhoore"]);
+        assert_eq!(event.teachers, Vec::<String>::new());
+        assert!(!event.workshop);
+        assert!(!event.social);
+        assert_eq!(event.sessions, expected_sessions);
+    }
+
+    #[test]
+    fn load_stored() {
+        let directory = std::env::temp_dir().join(format!("dancelist-{}", Event::new_id()));
+        std::fs::create_dir(&directory).unwrap();
+        std::fs::write(
+            directory.join("venues.yaml"),
+            r#"
+venues:
+  - id: shelter-hall
+    name: Shelter Hall
+    city: Dunedin
+    country: New Zealand
+"#,
+        )
+        .unwrap();
+        let filename = directory.join("events.yaml");
+        std::fs::write(
+            &filename,
+            r#"
+events:
+  - name: Barn dance
+    start_date: 2026-03-07
+    end_date: 2026-03-07
+    venue_id: shelter-hall
+    styles: [contra]
+    social: true
+    recurrence:
+      rrule: FREQ=WEEKLY;COUNT=3
+"#,
+        )
+        .unwrap();
+
+        let stored = Events::load_file(&filename, LoadMode::Stored).unwrap();
+        let served = Events::load_file(
+            &filename,
+            LoadMode::Serve(RecurrenceWindow {
+                past_days: 100_000,
+                future_days: 100_000,
+            }),
+        )
+        .unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        // The stored event is kept as it is, apart from its source.
+        assert_eq!(stored.events.len(), 1);
+        let event = &stored.events[0];
+        assert_eq!(event.city, "");
+        assert_eq!(event.venue, None);
+        assert_eq!(
+            event.recurrence.as_ref().unwrap().rrule,
+            "FREQ=WEEKLY;COUNT=3"
+        );
+        assert_eq!(event.source.as_deref(), filename.to_str());
+
+        assert_eq!(served.events.len(), 3);
+        assert_eq!(served.events[0].city, "Dunedin");
+        assert_eq!(served.events[0].recurrence, None);
+    }
+
+    #[test]
+    fn registry_scope() {
+        let directory = std::env::temp_dir().join(format!("dancelist-{}", Event::new_id()));
+        let root = directory.join("events");
+        for country in ["new-zealand", "uk"] {
+            std::fs::create_dir_all(root.join(country)).unwrap();
+        }
+        std::fs::write(
+            root.join("new-zealand/venues.yaml"),
+            r#"
+venues:
+  - id: shelter-hall
+    name: Shelter Hall
+    city: Dunedin
+    country: New Zealand
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("venues.yaml"),
+            r#"
+venues:
+  - id: outside
+    name: Outside
+    city: London
+    country: UK
+"#,
+        )
+        .unwrap();
+        let events = |venue_id: &str| {
+            format!(
+                "events:\n  - name: Barn dance\n    start_date: 2026-03-07\n    \
+                 end_date: 2026-03-07\n    venue_id: {venue_id}\n    styles: [contra]\n    social: true\n"
+            )
+        };
+        // Loads the given events file both on its own and as part of the whole directory.
+        let load = |country: &str, venue_id: &str| {
+            let filename = root.join(country).join("events.yaml");
+            std::fs::write(&filename, events(venue_id)).unwrap();
+            let loaded = (
+                Events::load_file(&filename, LoadMode::Stored).is_ok(),
+                Events::load_directory(&root, LoadMode::Stored).is_ok(),
+            );
+            std::fs::remove_file(&filename).unwrap();
+            loaded
+        };
+
+        // Venues in the same directory can be used.
+        assert_eq!(load("new-zealand", "shelter-hall"), (true, true));
+        // Venues in a sibling directory can't.
+        assert_eq!(load("uk", "shelter-hall"), (false, false));
+        // Nor can venues outside the events root.
+        assert_eq!(load("uk", "outside"), (false, false));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn normalise_names() {
+        let registries = Registries {
+            organisations: Organisations::load_str(
+                r#"
+organisations:
+  - name: Folkdance Dunedin
+    aliases: [FDD]
+"#,
+            )
+            .unwrap(),
+            bands: Bands::new(vec![Performer {
+                name: "The Contra Band".to_string(),
+                aliases: vec!["Contra Band".to_string()],
+                country: None,
+                website: None,
+            }]),
+            ..Default::default()
+        };
+        let mut events = Events::load_str(
+            r#"
+events:
+  - name: Barn dance
+    start_date: 2026-03-07
+    end_date: 2026-03-07
+    country: New Zealand
+    city: Dunedin
+    styles: [contra]
+    social: true
+    organisation: FDD
+    bands: [contra band]
+  - name: Bal
+    start_date: 2026-03-08
+    end_date: 2026-03-08
+    country: New Zealand
+    city: Dunedin
+    styles: [balfolk]
+    social: true
+    organisation: Someone else
+"#,
+        )
+        .unwrap();
+
+        events.normalise_names(&registries);
+        assert_eq!(
+            events.events[0].organisation.as_deref(),
+            Some("Folkdance Dunedin")
+        );
+        assert_eq!(
+            events.events[1].organisation.as_deref(),
+            Some("Someone else")
+        );
+    }
+
+    #[test]
+    fn bands_include_registry() {
+        let events = Events::load_str(
+            r#"
+events:
+  - name: Bal
+    start_date: 2026-03-08
+    end_date: 2026-03-08
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+    bands: [Naragonia, Trio Dhoore]
+"#,
+        )
+        .unwrap();
+        let known = Bands::new(vec![
+            Performer {
+                name: "Naragonia".to_string(),
+                aliases: vec![],
+                country: Some("Belgium".to_string()),
+                website: None,
+            },
+            Performer {
+                name: "Andromeda".to_string(),
+                aliases: vec![],
+                country: None,
+                website: None,
+            },
+        ]);
+
+        assert_eq!(
+            events.bands(&known),
+            vec![
+                Band {
+                    name: "Andromeda".to_string(),
+                    upcoming_count: 0,
+                    past_count: 0,
+                    country: None,
+                    website: None,
+                },
+                Band {
+                    name: "Naragonia".to_string(),
+                    upcoming_count: 0,
+                    past_count: 1,
+                    country: Some("Belgium".to_string()),
+                    website: None,
+                },
+                Band {
+                    name: "Trio Dhoore".to_string(),
+                    upcoming_count: 0,
+                    past_count: 1,
+                    country: None,
+                    website: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn performer_counts_skip_cancelled() {
+        let events = Events::load_str(
+            r#"
+events:
+  - name: Bal
+    start_date: 2099-03-08
+    end_date: 2099-03-08
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+    bands: [Naragonia]
+  - name: Bal
+    start_date: 2099-03-15
+    end_date: 2099-03-15
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+    bands: [Naragonia]
+    status: cancelled
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            events.bands(&Bands::default()),
+            vec![Band {
+                name: "Naragonia".to_string(),
+                upcoming_count: 1,
+                past_count: 0,
+                country: None,
+                website: None,
+            }]
+        );
+    }
 }
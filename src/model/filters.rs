@@ -15,21 +15,50 @@
 use super::{
     dancestyle::DanceStyle,
     event::{Event, EventTime},
+    inclusivity::{Accessibility, Level, RoleTerms},
+    price::Price,
+    status::Status,
 };
-use chrono::{DateTime, Utc};
+use crate::util::{date_or_none, fold_for_search};
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc, Weekday};
 use enum_iterator::{Sequence, all};
 use eyre::Report;
-use serde::{Deserialize, Deserializer, Serialize, Serializer, de::IntoDeserializer};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{Error, IntoDeserializer},
+};
 use std::{
     cmp::Ordering,
     collections::HashSet,
     fmt::{self, Display, Formatter},
 };
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+/// The radius to use for the `near` filter if none is specified.
+const DEFAULT_RADIUS_KM: u32 = 50;
+
+/// The radii to offer for the `near` filter on the index page.
+pub const RADIUS_OPTIONS_KM: [u32; 5] = [10, 25, 50, 100, 250];
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct Filters {
     #[serde(default, skip_serializing_if = "is_default")]
     pub date: DateFilter,
+    /// Only include events which finish on or after this date. If this or `to` is set then `date`
+    /// is ignored.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "date_or_none"
+    )]
+    pub from: Option<NaiveDate>,
+    /// Only include events which start on or before this date. If this or `from` is set then
+    /// `date` is ignored.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "date_or_none"
+    )]
+    pub to: Option<NaiveDate>,
     #[serde(
         default,
         skip_serializing_if = "HashSet::is_empty",
@@ -59,16 +88,137 @@ pub struct Filters {
         deserialize_with = "styles_de"
     )]
     pub styles: HashSet<DanceStyle>,
+    /// Exclude events in any of these countries.
+    #[serde(
+        alias = "-country",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "strings_ser",
+        deserialize_with = "strings_de"
+    )]
+    pub exclude_country: HashSet<String>,
+    /// Exclude events in any of these states.
+    #[serde(
+        alias = "-state",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "strings_ser",
+        deserialize_with = "strings_de"
+    )]
+    pub exclude_state: HashSet<String>,
+    /// Exclude events in any of these cities.
+    #[serde(
+        alias = "-city",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "strings_ser",
+        deserialize_with = "strings_de"
+    )]
+    pub exclude_city: HashSet<String>,
+    /// Exclude events which include any of these styles.
+    #[serde(
+        alias = "-styles",
+        alias = "-style",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "styles_ser",
+        deserialize_with = "styles_de"
+    )]
+    pub exclude_styles: HashSet<DanceStyle>,
+    /// Exclude events at which any of these bands play.
+    #[serde(
+        alias = "-band",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "strings_ser",
+        deserialize_with = "strings_de"
+    )]
+    pub exclude_band: HashSet<String>,
+    /// Exclude events run by any of these organisations.
+    #[serde(
+        alias = "-organisation",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "strings_ser",
+        deserialize_with = "strings_de"
+    )]
+    pub exclude_organisation: HashSet<String>,
     pub multiday: Option<bool>,
     pub workshop: Option<bool>,
     pub social: Option<bool>,
+    /// Only include events which can (or with `false`, can't) be joined online, either because
+    /// they are online-only or hybrid.
+    pub online: Option<bool>,
     pub band: Option<String>,
     pub caller: Option<String>,
+    pub teacher: Option<String>,
     pub organisation: Option<String>,
+    /// Only include events aimed at this level of experience, including events for all levels.
+    pub level: Option<Level>,
+    /// Only include events which use these terms for dancers' roles.
+    pub role_terms: Option<RoleTerms>,
+    pub no_partner_needed: Option<bool>,
+    pub family_friendly: Option<bool>,
+    /// Only include events whose venue is known to have this accessibility feature.
+    pub accessibility: Option<Accessibility>,
+    /// Only include events with any of these statuses.
+    #[serde(
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "statuses_ser",
+        deserialize_with = "statuses_de"
+    )]
+    pub status: HashSet<Status>,
+    /// Exclude events with any of these statuses.
+    #[serde(
+        alias = "-status",
+        default,
+        skip_serializing_if = "HashSet::is_empty",
+        serialize_with = "statuses_ser",
+        deserialize_with = "statuses_de"
+    )]
+    pub exclude_status: HashSet<Status>,
+    /// Deprecated: `cancelled=true` is the same as `status=cancelled`, and `cancelled=false` the
+    /// same as `-status=cancelled`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cancelled: Option<bool>,
+    /// Only include events which can (or with `false`, can't) be attended without paying, either
+    /// because they are free or donation-based. Events without a known price are excluded either
+    /// way.
+    pub free: Option<bool>,
+    /// Only include events whose lowest price is at most this amount in `currency`. Free and
+    /// donation-based events are included, events without a known price or priced in another
+    /// currency are not.
+    pub max_price: Option<u32>,
+    /// The ISO 4217 code of the currency of `max_price`, e.g. "EUR".
+    pub currency: Option<String>,
+    /// Only include events whose name, details, bands, callers, teachers, organisation, city or
+    /// price contain all the words of this search query, ignoring case and accents.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "trimmed_non_empty"
+    )]
+    pub q: Option<String>,
+    /// Only include events within `radius_km` of the given latitude and longitude.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "coordinates_ser",
+        deserialize_with = "coordinates_de"
+    )]
+    pub near: Option<(f64, f64)>,
+    pub radius_km: Option<u32>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub sort: SortOrder,
+    /// Whether to show regular events in the same series as a single entry. Defaults to true.
+    pub collapse: Option<bool>,
     pub limit: Option<usize>,
 }
 
+// `coordinates_de` only accepts finite coordinates, so equality is reflexive.
+impl Eq for Filters {}
+
 fn styles_ser<S: Serializer>(
     styles: &HashSet<DanceStyle>,
     serializer: S,
@@ -87,6 +237,24 @@ fn styles_de<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashSet<Dance
         .collect()
 }
 
+fn statuses_ser<S: Serializer>(
+    statuses: &HashSet<Status>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tags: Vec<_> = statuses.iter().map(|status| status.tag()).collect();
+    // Sort so as to maintain a consistent serialisation.
+    tags.sort();
+    serializer.serialize_str(&tags.join(","))
+}
+
+fn statuses_de<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashSet<Status>, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    string
+        .split(',')
+        .map(|tag| Status::deserialize(tag.into_deserializer()))
+        .collect()
+}
+
 fn strings_ser<S: Serializer>(strings: &HashSet<String>, serializer: S) -> Result<S::Ok, S::Error> {
     let mut strings: Vec<_> = strings.iter().map(ToOwned::to_owned).collect();
     // Sort so as to maintain a consistent serialisation.
@@ -99,12 +267,62 @@ fn strings_de<'de, D: Deserializer<'de>>(deserializer: D) -> Result<HashSet<Stri
     Ok(string.split(',').map(|item| item.to_owned()).collect())
 }
 
+fn coordinates_ser<S: Serializer>(
+    coordinates: &Option<(f64, f64)>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let (latitude, longitude) = coordinates.unwrap();
+    serializer.serialize_str(&format!("{latitude},{longitude}"))
+}
+
+fn coordinates_de<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<(f64, f64)>, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    let parse = |part: &str| part.trim().parse::<f64>().ok();
+    match string.split_once(',') {
+        Some((latitude, longitude)) => match (parse(latitude), parse(longitude)) {
+            // This also rejects NaN and infinite values.
+            (Some(latitude), Some(longitude))
+                if (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude) =>
+            {
+                Ok(Some((latitude, longitude)))
+            }
+            _ => Err(D::Error::custom(format!("Invalid coordinates {string:?}"))),
+        },
+        None => Err(D::Error::custom(format!(
+            "Coordinates {string:?} should be of the form <latitude>,<longitude>"
+        ))),
+    }
+}
+
+fn trimmed_non_empty<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    let trimmed = string.trim();
+    Ok(if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_owned())
+    })
+}
+
 #[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Sequence, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DateFilter {
-    /// Include only events which started before the current day.
+    /// Include only events which started before the current day in their local timezone.
     Past,
-    /// Include only events which finish on or after the current day.
+    /// Include only events on the current day which haven't yet finished.
+    Today,
+    /// Include only events between Friday and Sunday of the current weekend, or the next weekend
+    /// if it is currently a weekday.
+    Weekend,
+    /// Include only events in the next 7 days, starting with the current day.
+    Week,
+    /// Include only events in the next 30 days, starting with the current day.
+    Month,
+    /// Include only events which finish on or after the current day in their local timezone.
     #[default]
     Future,
     /// Include all events, past and future.
@@ -115,12 +333,37 @@ impl DateFilter {
     pub fn values() -> impl Iterator<Item = Self> {
         all::<DateFilter>()
     }
+
+    /// Returns the first and last day of the window covered by a relative date filter, given the
+    /// current date, or `None` if this is not a relative window.
+    pub fn window(self, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match self {
+            Self::Today => Some((today, today)),
+            Self::Weekend => {
+                let weekday = today.weekday();
+                let start = if weekday.number_from_monday() >= Weekday::Fri.number_from_monday() {
+                    today
+                } else {
+                    today + Days::new(Weekday::Fri.days_since(weekday).into())
+                };
+                let end = today + Days::new(Weekday::Sun.days_since(weekday).into());
+                Some((start, end))
+            }
+            Self::Week => Some((today, today + Days::new(6))),
+            Self::Month => Some((today, today + Days::new(29))),
+            Self::Past | Self::Future | Self::All => None,
+        }
+    }
 }
 
 impl Display for DateFilter {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let s = match self {
             Self::Past => "past",
+            Self::Today => "today",
+            Self::Weekend => "this weekend",
+            Self::Week => "next 7 days",
+            Self::Month => "next 30 days",
             Self::Future => "future",
             Self::All => "all",
         };
@@ -128,6 +371,18 @@ impl Display for DateFilter {
     }
 }
 
+/// The order in which to return matching events.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Sort by start time then location.
+    #[default]
+    Date,
+    /// Sort by distance from the `near` filter, nearest first. Falls back to date order if there
+    /// is no `near` filter.
+    Distance,
+}
+
 impl Filters {
     pub fn all() -> Self {
         Self {
@@ -137,40 +392,110 @@ impl Filters {
     }
 
     pub fn has_some(&self) -> bool {
-        !self.country.is_empty()
+        self.has_date_range()
+            || !self.country.is_empty()
             || !self.state.is_empty()
             || !self.city.is_empty()
             || !self.styles.is_empty()
+            || self.has_exclusions()
             || self.multiday.is_some()
             || self.workshop.is_some()
             || self.social.is_some()
+            || self.online.is_some()
             || self.band.is_some()
             || self.caller.is_some()
+            || self.teacher.is_some()
             || self.organisation.is_some()
+            || self.level.is_some()
+            || self.role_terms.is_some()
+            || self.no_partner_needed.is_some()
+            || self.family_friendly.is_some()
+            || self.accessibility.is_some()
+            || !self.status.is_empty()
+            || !self.exclude_status.is_empty()
             || self.cancelled.is_some()
+            || self.free.is_some()
+            || self.max_price.is_some()
+            || self.q.is_some()
+            || self.near.is_some()
             || self.limit.is_some()
     }
 
+    /// Returns whether regular events in the same series should be shown as a single entry.
+    pub fn collapse_series(&self) -> bool {
+        self.collapse.unwrap_or(true)
+    }
+
+    /// Returns whether any countries, states, cities, styles, bands or organisations are excluded.
+    pub fn has_exclusions(&self) -> bool {
+        !self.exclude_country.is_empty()
+            || !self.exclude_state.is_empty()
+            || !self.exclude_city.is_empty()
+            || !self.exclude_styles.is_empty()
+            || !self.exclude_band.is_empty()
+            || !self.exclude_organisation.is_empty()
+    }
+
+    /// Returns whether an explicit `from` or `to` date has been set.
+    pub fn has_date_range(&self) -> bool {
+        self.from.is_some() || self.to.is_some()
+    }
+
     pub fn to_query_string(&self) -> Result<String, Report> {
         Ok(serde_urlencoded::to_string(self)?)
     }
 
+    /// Returns the filters as a list of query parameter names and values, e.g. for hidden form
+    /// fields.
+    pub fn to_query_pairs(&self) -> Result<Vec<(String, String)>, Report> {
+        Ok(
+            url::form_urlencoded::parse(self.to_query_string()?.as_bytes())
+                .into_owned()
+                .collect(),
+        )
+    }
+
     pub fn matches(&self, event: &Event, now: DateTime<Utc>) -> bool {
-        let today = now.naive_utc().date();
-        match event.time {
-            EventTime::DateOnly {
-                start_date,
-                end_date,
-            } => match self.date {
-                DateFilter::Future if end_date < today => return false,
-                DateFilter::Past if start_date >= today => return false,
-                _ => {}
-            },
-            EventTime::DateTime { start, end } => match self.date {
-                DateFilter::Future if end < now => return false,
-                DateFilter::Past if start >= now => return false,
-                _ => {}
-            },
+        if self.has_date_range() {
+            if let Some(from) = self.from
+                && event.time.end_date() < from
+            {
+                return false;
+            }
+            if let Some(to) = self.to
+                && event.time.start_date() > to
+            {
+                return false;
+            }
+        } else if let Some((window_start, window_end)) = self.date.window(event.local_date(now)) {
+            if event.time.end_date() < window_start || event.time.start_date() > window_end {
+                return false;
+            }
+            if let EventTime::DateTime { end, .. } = event.time
+                && end < now
+            {
+                return false;
+            }
+        } else {
+            match event.time {
+                EventTime::DateOnly {
+                    start_date,
+                    end_date,
+                } => {
+                    // Compare against the current date where the event is, not in UTC.
+                    let today = event.local_date(now);
+                    match self.date {
+                        DateFilter::Future if end_date < today => return false,
+                        DateFilter::Past if start_date >= today => return false,
+                        _ => {}
+                    }
+                }
+                EventTime::DateTime { start, end } => match self.date {
+                    DateFilter::Future if end < now => return false,
+                    DateFilter::Past if start >= now => return false,
+                    _ => {}
+                },
+            }
         }
 
         if !self.country.is_empty() && !self.country.contains(&event.country) {
@@ -190,6 +515,27 @@ impl Filters {
         {
             return false;
         }
+        if self.exclude_country.contains(&event.country)
+            || event
+                .state
+                .as_ref()
+                .is_some_and(|state| self.exclude_state.contains(state))
+            || self.exclude_city.contains(&event.city)
+            || event
+                .styles
+                .iter()
+                .any(|style| self.exclude_styles.contains(style))
+            || event
+                .bands
+                .iter()
+                .any(|band| self.exclude_band.contains(band))
+            || event
+                .organisation
+                .as_ref()
+                .is_some_and(|organisation| self.exclude_organisation.contains(organisation))
+        {
+            return false;
+        }
         if let Some(multiday) = self.multiday
             && event.multiday() != multiday
         {
@@ -205,6 +551,11 @@ impl Filters {
         {
             return false;
         }
+        if let Some(online) = self.online
+            && event.attendance.online() != online
+        {
+            return false;
+        }
         if let Some(band) = &self.band
             && !event.bands.contains(band)
         {
@@ -215,20 +566,111 @@ impl Filters {
         {
             return false;
         }
+        if let Some(teacher) = &self.teacher
+            && !event.teachers.contains(teacher)
+        {
+            return false;
+        }
         if let Some(organisation) = &self.organisation
             && event.organisation.as_deref().unwrap_or_default() != organisation
         {
             return false;
         }
+        if let Some(level) = self.level
+            && !event
+                .level
+                .is_some_and(|event_level| event_level.suits(level))
+        {
+            return false;
+        }
+        if let Some(role_terms) = self.role_terms
+            && event.role_terms != Some(role_terms)
+        {
+            return false;
+        }
+        if let Some(no_partner_needed) = self.no_partner_needed
+            && event.no_partner_needed != no_partner_needed
+        {
+            return false;
+        }
+        if let Some(family_friendly) = self.family_friendly
+            && event.family_friendly != family_friendly
+        {
+            return false;
+        }
+        if let Some(accessibility) = self.accessibility
+            && !event.accessibility.contains(&accessibility)
+        {
+            return false;
+        }
+        if !self.status.is_empty() && !self.status.contains(&event.status) {
+            return false;
+        }
+        if self.exclude_status.contains(&event.status) {
+            return false;
+        }
         if let Some(cancelled) = self.cancelled
-            && event.cancelled != cancelled
+            && (event.status == Status::Cancelled) != cancelled
+        {
+            return false;
+        }
+        if let Some(free) = self.free
+            && event
+                .price
+                .as_ref()
+                .filter(|price| price.lowest().is_some())
+                .map(Price::is_free)
+                != Some(free)
         {
             return false;
         }
+        if let Some(max_price) = self.max_price
+            && !event
+                .price
+                .as_ref()
+                .is_some_and(|price| self.within_max_price(price, max_price))
+        {
+            return false;
+        }
+        if let Some(q) = &self.q
+            && !search_matches(q, event)
+        {
+            return false;
+        }
+        if let Some(near) = self.near {
+            // Events without coordinates can't be shown to be nearby, so exclude them.
+            match event.distance_km(near) {
+                Some(distance) if distance <= f64::from(self.radius_km()) => {}
+                _ => return false,
+            }
+        }
 
         true
     }
 
+    /// Returns whether the given price is at most `max_price` in the filter's currency. Amounts in
+    /// different currencies can't be compared, so only free and donation-based events match if
+    /// the price or the filter has no currency or they differ.
+    fn within_max_price(&self, price: &Price, max_price: u32) -> bool {
+        if price.free || price.donation {
+            return true;
+        }
+        let same_currency = price
+            .currency
+            .as_ref()
+            .zip(self.currency.as_ref())
+            .is_some_and(|(a, b)| a.eq_ignore_ascii_case(b));
+        same_currency
+            && price
+                .lowest()
+                .is_some_and(|lowest| lowest <= f64::from(max_price))
+    }
+
+    /// Returns the radius for the `near` filter, or the default if none was specified.
+    pub fn radius_km(&self) -> u32 {
+        self.radius_km.unwrap_or(DEFAULT_RADIUS_KM)
+    }
+
     /// Returns a list of the styles, or "Folk dance".
     pub fn styles_string(&self) -> String {
         if self.styles.is_empty() {
@@ -264,7 +706,7 @@ impl Filters {
             Some(join_cities(&self.city))
         };
 
-        match (countries, states, cities) {
+        let mut title = match (countries, states, cities) {
             (None, None, None) => format!("{style} events"),
             (Some(countries), None, None) => {
                 if countries == "UK" || countries == "USA" {
@@ -289,7 +731,114 @@ impl Filters {
             (Some(country), Some(states), Some(cities)) => {
                 format!("{style} events in {cities}, {states}, {country}")
             }
+        };
+
+        if !self.exclude_country.is_empty()
+            || !self.exclude_state.is_empty()
+            || !self.exclude_city.is_empty()
+        {
+            let mut places: Vec<_> = [&self.exclude_city, &self.exclude_state]
+                .into_iter()
+                .flat_map(sorted_strings)
+                .collect();
+            places.extend(
+                sorted_strings(&self.exclude_country)
+                    .into_iter()
+                    .map(|country| {
+                        if country == "UK" || country == "USA" {
+                            format!("the {country}")
+                        } else {
+                            country
+                        }
+                    }),
+            );
+            title += &format!(" outside {}", join_words(&places));
+        }
+        if !self.exclude_styles.is_empty() {
+            let mut styles: Vec<_> = self.exclude_styles.iter().collect();
+            styles.sort();
+            let styles: Vec<_> = styles
+                .into_iter()
+                .map(|style| style.name().to_owned())
+                .collect();
+            title += &format!(" except {}", join_words(&styles));
+        }
+        if !self.exclude_band.is_empty() {
+            title += &format!(
+                " without {}",
+                join_words(&sorted_strings(&self.exclude_band))
+            );
+        }
+        if !self.exclude_organisation.is_empty() {
+            title += &format!(
+                " not run by {}",
+                join_words(&sorted_strings(&self.exclude_organisation))
+            );
+        }
+        match self.online {
+            Some(true) => title += " online",
+            Some(false) => title += " in person only",
+            None => {}
+        }
+        match self.level {
+            Some(Level::Beginner) => title += " for beginners",
+            Some(Level::All) => title += " for all levels",
+            Some(Level::Advanced) => title += " for advanced dancers",
+            None => {}
+        }
+        if let Some(role_terms) = self.role_terms {
+            title += &format!(" with {role_terms}");
+        }
+        match self.no_partner_needed {
+            Some(true) => title += " with no partner needed",
+            Some(false) => title += " needing a partner",
+            None => {}
+        }
+        match self.family_friendly {
+            Some(true) => title += " for families",
+            Some(false) => title += " not for families",
+            None => {}
+        }
+        if let Some(accessibility) = self.accessibility {
+            title += &format!(" with {accessibility}");
+        }
+        match self.free {
+            Some(true) => title += " free to attend",
+            Some(false) => title += " which aren't free",
+            None => {}
+        }
+        if let Some(max_price) = self.max_price {
+            title += &format!(" costing at most {max_price}");
+            if let Some(currency) = &self.currency {
+                title += &format!(" {currency}");
+            }
+        }
+        if let Some((latitude, longitude)) = self.near {
+            title += &format!(
+                " within {} km of {latitude:.4}, {longitude:.4}",
+                self.radius_km()
+            );
+        }
+        if let Some(q) = &self.q {
+            title += &format!(" matching \"{q}\"");
+        }
+        match self.date {
+            _ if self.has_date_range() => {}
+            DateFilter::Today => title += " today",
+            DateFilter::Weekend => title += " this weekend",
+            DateFilter::Week => title += " in the next 7 days",
+            DateFilter::Month => title += " in the next 30 days",
+            DateFilter::Past | DateFilter::Future | DateFilter::All => {}
+        }
+        match (self.from, self.to) {
+            (Some(from), Some(to)) => {
+                title += &format!(" from {} to {}", format_date(from), format_date(to));
+            }
+            (Some(from), None) => title += &format!(" from {}", format_date(from)),
+            (None, Some(to)) => title += &format!(" until {}", format_date(to)),
+            (None, None) => {}
         }
+        title
     }
 
     /// Makes a new set of filters like this one but with the given country filter and no state or
@@ -328,10 +877,60 @@ impl Filters {
         }
     }
 
-    /// Makes a new set of filters like this one but with the given date filter.
+    /// Makes a new set of filters like this one but with the given date filter and no date range.
     pub fn with_date(&self, date: DateFilter) -> Self {
         Self {
             date,
+            from: None,
+            to: None,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given date range.
+    pub fn with_date_range(&self, from: Option<NaiveDate>, to: Option<NaiveDate>) -> Self {
+        Self {
+            from,
+            to,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given search query.
+    pub fn with_search(&self, q: Option<String>) -> Self {
+        Self { q, ..self.clone() }
+    }
+
+    /// Makes a new set of filters like this one but with the given radius for the `near` filter.
+    pub fn with_radius_km(&self, radius_km: Option<u32>) -> Self {
+        Self {
+            radius_km,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with no `near` filter.
+    pub fn without_near(&self) -> Self {
+        Self {
+            near: None,
+            radius_km: None,
+            sort: SortOrder::Date,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given sort order.
+    pub fn with_sort(&self, sort: SortOrder) -> Self {
+        Self {
+            sort,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given series collapsing option.
+    pub fn with_collapse(&self, collapse: Option<bool>) -> Self {
+        Self {
+            collapse,
             ..self.clone()
         }
     }
@@ -359,6 +958,71 @@ impl Filters {
             ..self.clone()
         }
     }
+
+    /// Makes a new set of filters like this one but with the given level filter.
+    pub fn with_level(&self, level: Option<Level>) -> Self {
+        Self {
+            level,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given role terms filter.
+    pub fn with_role_terms(&self, role_terms: Option<RoleTerms>) -> Self {
+        Self {
+            role_terms,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given no partner needed filter.
+    pub fn with_no_partner_needed(&self, no_partner_needed: Option<bool>) -> Self {
+        Self {
+            no_partner_needed,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given family-friendly filter.
+    pub fn with_family_friendly(&self, family_friendly: Option<bool>) -> Self {
+        Self {
+            family_friendly,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given accessibility filter.
+    pub fn with_accessibility(&self, accessibility: Option<Accessibility>) -> Self {
+        Self {
+            accessibility,
+            ..self.clone()
+        }
+    }
+
+    /// Makes a new set of filters like this one but with the given online filter.
+    pub fn with_online(&self, online: Option<bool>) -> Self {
+        Self {
+            online,
+            ..self.clone()
+        }
+    }
+}
+
+/// Returns whether all words of the given search query are found in the searchable fields of the
+/// event, ignoring case and accents.
+fn search_matches(q: &str, event: &Event) -> bool {
+    let price = event.price.as_ref().map(ToString::to_string);
+    let mut fields = vec![event.name.as_str(), event.city.as_str()];
+    fields.extend(event.details.as_deref());
+    fields.extend(event.bands.iter().map(String::as_str));
+    fields.extend(event.callers.iter().map(String::as_str));
+    fields.extend(event.teachers.iter().map(String::as_str));
+    fields.extend(event.organisation.as_deref());
+    fields.extend(price.as_deref());
+    let haystack = fold_for_search(&fields.join("\n"));
+    fold_for_search(q)
+        .split_whitespace()
+        .all(|word| haystack.contains(word))
 }
 
 /// Make the first letter of the given string uppercase.
@@ -371,14 +1035,23 @@ fn uppercase_first_letter(s: &str) -> String {
     }
 }
 
+/// Formats the given date for a page title, like "1 March 2025".
+fn format_date(date: NaiveDate) -> String {
+    date.format("%-d %B %Y").to_string()
+}
+
 fn is_default<T: Default + PartialEq>(value: &T) -> bool {
     value == &T::default()
 }
 
 fn join_cities(cities: &HashSet<String>) -> String {
-    let mut cities: Vec<_> = cities.iter().map(ToOwned::to_owned).collect();
-    cities.sort();
-    join_words(&cities)
+    join_words(&sorted_strings(cities))
+}
+
+fn sorted_strings(strings: &HashSet<String>) -> Vec<String> {
+    let mut strings: Vec<_> = strings.iter().map(ToOwned::to_owned).collect();
+    strings.sort();
+    strings
 }
 
 fn join_words(parts: &[String]) -> String {
@@ -401,6 +1074,9 @@ fn join_words(parts: &[String]) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::{attendance::AttendanceMode, event::test_event, inclusivity::Level};
+    use chrono::TimeZone;
+    use chrono_tz::Tz;
 
     #[test]
     fn empty_filters_title() {
@@ -558,4 +1234,647 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn deserialize_near() {
+        let query_string = "near=50.7753%2C6.0839&radius_km=30";
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>(query_string).unwrap(),
+            Filters {
+                near: Some((50.7753, 6.0839)),
+                radius_km: Some(30),
+                ..Default::default()
+            }
+        );
+        assert!(serde_urlencoded::from_str::<Filters>("near=50.7753").is_err());
+        assert!(serde_urlencoded::from_str::<Filters>("near=NaN%2C6").is_err());
+        assert!(serde_urlencoded::from_str::<Filters>("near=200%2C500").is_err());
+        assert!(serde_urlencoded::from_str::<Filters>("near=-90.5%2C6").is_err());
+        assert!(serde_urlencoded::from_str::<Filters>("near=50%2C-180.5").is_err());
+        assert!(serde_urlencoded::from_str::<Filters>("near=-90%2C180").is_ok());
+    }
+
+    #[test]
+    fn near_query_string() {
+        let filters = Filters {
+            near: Some((50.7753, 6.0839)),
+            sort: SortOrder::Distance,
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.to_query_string().unwrap(),
+            "near=50.7753%2C6.0839&sort=distance"
+        );
+    }
+
+    #[test]
+    fn near_title() {
+        let filters = Filters {
+            styles: [DanceStyle::Balfolk].into_iter().collect(),
+            near: Some((50.7753, 6.0839)),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.make_title(),
+            "Balfolk events within 50 km of 50.7753, 6.0839"
+        );
+    }
+
+    #[test]
+    fn deserialize_date_range() {
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>("from=2025-03-01&to=2025-03-31").unwrap(),
+            Filters {
+                from: NaiveDate::from_ymd_opt(2025, 3, 1),
+                to: NaiveDate::from_ymd_opt(2025, 3, 31),
+                ..Default::default()
+            }
+        );
+        // Empty dates from the form should be ignored.
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>("from=&to=&date=past").unwrap(),
+            Filters {
+                date: DateFilter::Past,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn date_range_title_query_string() {
+        let filters = Filters {
+            city: ["Berlin".to_string()].into_iter().collect(),
+            from: NaiveDate::from_ymd_opt(2025, 3, 1),
+            to: NaiveDate::from_ymd_opt(2025, 3, 31),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.make_title(),
+            "Folk dance events in Berlin from 1 March 2025 to 31 March 2025"
+        );
+        assert_eq!(
+            filters.to_query_string().unwrap(),
+            "from=2025-03-01&to=2025-03-31&city=Berlin"
+        );
+        assert_eq!(
+            Filters {
+                to: NaiveDate::from_ymd_opt(2025, 3, 31),
+                ..Default::default()
+            }
+            .make_title(),
+            "Folk dance events until 31 March 2025"
+        );
+    }
+
+    #[test]
+    fn match_date_range() {
+        let event = Event {
+            name: "Festival".to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2020, 7, 2).unwrap(),
+            },
+            workshop: true,
+            ..test_event()
+        };
+        let now = Utc::now();
+        let range = |from, to| Filters {
+            from: NaiveDate::from_ymd_opt(2020, from, 1),
+            to: NaiveDate::from_ymd_opt(2020, to, 1).map(|to| to.pred_opt().unwrap()),
+            ..Default::default()
+        };
+
+        // The default future filter is ignored once a range is given.
+        assert!(range(6, 7).matches(&event, now));
+        assert!(range(7, 8).matches(&event, now));
+        assert!(!range(5, 6).matches(&event, now));
+        assert!(!range(8, 9).matches(&event, now));
+    }
+
+    #[test]
+    fn relative_windows() {
+        // A Wednesday.
+        let wednesday = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+        let friday = NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2025, 3, 8).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+
+        assert_eq!(
+            DateFilter::Today.window(wednesday),
+            Some((wednesday, wednesday))
+        );
+        assert_eq!(
+            DateFilter::Weekend.window(wednesday),
+            Some((friday, sunday))
+        );
+        assert_eq!(
+            DateFilter::Weekend.window(saturday),
+            Some((saturday, sunday))
+        );
+        assert_eq!(DateFilter::Weekend.window(sunday), Some((sunday, sunday)));
+        assert_eq!(
+            DateFilter::Week.window(wednesday),
+            NaiveDate::from_ymd_opt(2025, 3, 11).map(|end| (wednesday, end))
+        );
+        assert_eq!(
+            DateFilter::Month.window(wednesday),
+            NaiveDate::from_ymd_opt(2025, 4, 3).map(|end| (wednesday, end))
+        );
+        assert_eq!(DateFilter::Future.window(wednesday), None);
+    }
+
+    #[test]
+    fn match_today_local_time() {
+        let make_event = |country: &str| Event {
+            name: "Dance".to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2025, 3, 6).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2025, 3, 6).unwrap(),
+            },
+            country: country.to_string(),
+            styles: vec![DanceStyle::Contra],
+            ..test_event()
+        };
+        let filters = Filters {
+            date: DateFilter::Today,
+            ..Default::default()
+        };
+        // It is already the 6th in New Zealand, but still the 5th in the UK.
+        let now = Utc.with_ymd_and_hms(2025, 3, 5, 11, 30, 0).unwrap();
+
+        assert!(filters.matches(&make_event("New Zealand"), now));
+        assert!(!filters.matches(&make_event("UK"), now));
+        assert_eq!(filters.make_title(), "Folk dance events today");
+    }
+
+    #[test]
+    fn match_future_past_local_time() {
+        let make_event = |country: &str, state: Option<&str>, timezone: Option<Tz>| Event {
+            name: "Dance".to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2025, 3, 5).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2025, 3, 5).unwrap(),
+            },
+            timezone,
+            country: country.to_string(),
+            state: state.map(ToOwned::to_owned),
+            styles: vec![DanceStyle::Contra],
+            ..test_event()
+        };
+        let new_zealand = make_event("New Zealand", None, None);
+        let uk = make_event("UK", None, None);
+        let california = make_event("USA", Some("CA"), None);
+        let explicit = make_event("Atlantis", None, Some(Tz::Asia__Tokyo));
+        let unknown = make_event("Atlantis", None, None);
+        let future = Filters::default();
+        let past = Filters {
+            date: DateFilter::Past,
+            ..Default::default()
+        };
+
+        // Just before midnight UTC at the end of the 5th, when it is already the 6th in New
+        // Zealand and Japan.
+        let now = Utc.with_ymd_and_hms(2025, 3, 5, 23, 59, 0).unwrap();
+        assert!(!future.matches(&new_zealand, now));
+        assert!(past.matches(&new_zealand, now));
+        assert!(!future.matches(&explicit, now));
+        assert!(future.matches(&uk, now));
+        assert!(future.matches(&california, now));
+        assert!(future.matches(&unknown, now));
+
+        // Just after midnight UTC, when it is the 6th in the UK but still the 5th in California.
+        let now = Utc.with_ymd_and_hms(2025, 3, 6, 0, 1, 0).unwrap();
+        assert!(!future.matches(&uk, now));
+        assert!(past.matches(&uk, now));
+        assert!(future.matches(&california, now));
+        assert!(!past.matches(&california, now));
+        assert!(!future.matches(&unknown, now));
+
+        // Just after midnight in California.
+        let now = Utc.with_ymd_and_hms(2025, 3, 6, 8, 1, 0).unwrap();
+        assert!(!future.matches(&california, now));
+        assert!(past.matches(&california, now));
+
+        // On the day itself in New Zealand, the event is neither past nor finished.
+        let now = Utc.with_ymd_and_hms(2025, 3, 4, 11, 1, 0).unwrap();
+        assert!(future.matches(&new_zealand, now));
+        assert!(!past.matches(&new_zealand, now));
+    }
+
+    #[test]
+    fn search() {
+        let event = Event {
+            name: "Grand Bal à Liège".to_string(),
+            details: Some("With workshops in the afternoon.".to_string()),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+            },
+            country: "Belgium".to_string(),
+            city: "Liège".to_string(),
+            workshop: true,
+            bands: vec!["Naragonia".to_string()],
+            price: Some("€15".into()),
+            organisation: Some("Folknam Musique Trad".to_string()),
+            ..test_event()
+        };
+        let now = Utc::now();
+        let search = |q: &str| {
+            Filters {
+                date: DateFilter::All,
+                q: Some(q.to_string()),
+                ..Default::default()
+            }
+            .matches(&event, now)
+        };
+
+        assert!(search("naragonia"));
+        assert!(search("LIEGE"));
+        assert!(search("bal naragonia"));
+        assert!(search("musique workshops"));
+        assert!(!search("naragonia contra"));
+        assert!(!search("berlin"));
+    }
+
+    #[test]
+    fn deserialize_search() {
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>("q=%20naragonia%20").unwrap(),
+            Filters {
+                q: Some("naragonia".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>("q=").unwrap(),
+            Filters::default()
+        );
+        assert_eq!(
+            Filters {
+                q: Some("naragonia".to_string()),
+                ..Default::default()
+            }
+            .make_title(),
+            "Folk dance events matching \"naragonia\""
+        );
+    }
+
+    #[test]
+    fn deserialize_exclusions() {
+        let expected = Filters {
+            country: ["Germany".to_string(), "France".to_string()]
+                .into_iter()
+                .collect(),
+            exclude_city: ["Berlin".to_string()].into_iter().collect(),
+            exclude_styles: [DanceStyle::ScottishCountryDance, DanceStyle::Contra]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>(
+                "country=Germany,France&-city=Berlin&-styles=scd,contra"
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>(
+                "country=Germany,France&exclude_city=Berlin&exclude_styles=scd,contra"
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            expected.to_query_string().unwrap(),
+            "country=France%2CGermany&exclude_city=Berlin&exclude_styles=contra%2Cscd"
+        );
+
+        let expected = Filters {
+            exclude_band: ["Naragonia".to_string()].into_iter().collect(),
+            exclude_organisation: ["Folkbal".to_string()].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_urlencoded::from_str::<Filters>("-band=Naragonia&-organisation=Folkbal").unwrap(),
+            expected
+        );
+        assert_eq!(
+            expected.to_query_string().unwrap(),
+            "exclude_band=Naragonia&exclude_organisation=Folkbal"
+        );
+    }
+
+    #[test]
+    fn exclusions_title() {
+        let filters = Filters {
+            country: ["Germany".to_string()].into_iter().collect(),
+            exclude_city: ["Berlin".to_string()].into_iter().collect(),
+            exclude_styles: [DanceStyle::ScottishCountryDance].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.make_title(),
+            "Folk dance events in Germany outside Berlin except SCD"
+        );
+
+        let filters = Filters {
+            styles: [DanceStyle::Contra].into_iter().collect(),
+            exclude_country: ["USA".to_string(), "Canada".to_string()]
+                .into_iter()
+                .collect(),
+            exclude_state: ["Wales".to_string()].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.make_title(),
+            "Contra events outside Wales, Canada and the USA"
+        );
+
+        let filters = Filters {
+            exclude_band: ["Naragonia".to_string(), "Duo Absynthe".to_string()]
+                .into_iter()
+                .collect(),
+            exclude_organisation: ["Folkbal".to_string()].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.make_title(),
+            "Folk dance events without Duo Absynthe and Naragonia not run by Folkbal"
+        );
+    }
+
+    #[test]
+    fn match_exclusions() {
+        let event = Event {
+            name: "Ceilidh".to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+            },
+            country: "UK".to_string(),
+            state: Some("Scotland".to_string()),
+            city: "Edinburgh".to_string(),
+            styles: vec![
+                DanceStyle::ScottishCeilidh,
+                DanceStyle::ScottishCountryDance,
+            ],
+            bands: vec!["Ceilidh Band".to_string(), "Other Band".to_string()],
+            organisation: Some("Ceilidh Club".to_string()),
+            ..test_event()
+        };
+        let now = Utc::now();
+        let matches = |query_string: &str| {
+            Filters {
+                date: DateFilter::All,
+                ..serde_urlencoded::from_str(query_string).unwrap()
+            }
+            .matches(&event, now)
+        };
+
+        assert!(matches(""));
+        assert!(matches(
+            "-country=USA&-state=Wales&-city=London&-styles=contra"
+        ));
+        assert!(!matches("-country=UK"));
+        assert!(!matches("-state=Scotland"));
+        assert!(!matches("-city=Edinburgh,Glasgow"));
+        assert!(!matches("-styles=scd"));
+        assert!(!matches("styles=s-ceilidh&-styles=scd"));
+        assert!(matches("-band=Ceilidh&-organisation=Folk Club"));
+        assert!(!matches("-band=Other Band"));
+        assert!(!matches("exclude_band=Ceilidh Band,Third Band"));
+        assert!(!matches("-organisation=Ceilidh Club"));
+        assert!(!matches("band=Ceilidh Band&-organisation=Ceilidh Club"));
+    }
+
+    #[test]
+    fn match_price() {
+        let mut event = Event {
+            name: "Bal".to_string(),
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2020, 6, 28).unwrap(),
+            },
+            country: "Belgium".to_string(),
+            city: "Gent".to_string(),
+            ..test_event()
+        };
+        let now = Utc::now();
+        let matches = |event: &Event, query_string: &str| {
+            Filters {
+                date: DateFilter::All,
+                ..serde_urlencoded::from_str(query_string).unwrap()
+            }
+            .matches(event, now)
+        };
+
+        // Events with no price aren't known to be free or cheap, or not free.
+        assert!(!matches(&event, "free=true"));
+        assert!(!matches(&event, "free=false"));
+        assert!(!matches(&event, "max_price=100&currency=EUR"));
+
+        event.price = Some("€8-€12".into());
+        assert!(!matches(&event, "free=true"));
+        assert!(matches(&event, "free=false"));
+        assert!(matches(&event, "max_price=8&currency=EUR"));
+        assert!(matches(&event, "max_price=8&currency=eur"));
+        assert!(!matches(&event, "max_price=7&currency=EUR"));
+        // Amounts in different or unknown currencies can't be compared.
+        assert!(!matches(&event, "max_price=100&currency=GBP"));
+        assert!(!matches(&event, "max_price=100"));
+
+        event.price = Some(Price::donation());
+        assert!(matches(&event, "free=true"));
+        assert!(!matches(&event, "free=false"));
+        assert!(matches(&event, "max_price=0"));
+        assert!(matches(&event, "max_price=0&currency=GBP"));
+
+        event.price = Some("ask at the door".into());
+        assert!(!matches(&event, "free=true"));
+        assert!(!matches(&event, "free=false"));
+        assert!(!matches(&event, "max_price=100&currency=EUR"));
+    }
+
+    #[test]
+    fn match_status() {
+        let event: Event = serde_yaml::from_str(
+            r#"
+name: Contra dance
+start_date: 2020-06-28
+end_date: 2020-06-28
+country: USA
+city: Boston
+styles: [contra]
+social: true
+status: postponed
+"#,
+        )
+        .unwrap();
+        let now = Utc::now();
+        let matches = |query_string: &str| {
+            Filters {
+                date: DateFilter::All,
+                ..serde_urlencoded::from_str(query_string).unwrap()
+            }
+            .matches(&event, now)
+        };
+
+        assert!(matches(""));
+        assert!(matches("status=postponed"));
+        assert!(matches("status=cancelled,postponed"));
+        assert!(!matches("status=scheduled"));
+        assert!(!matches("-status=postponed"));
+        assert!(matches("-status=cancelled"));
+        assert!(matches("cancelled=false"));
+        assert!(!matches("cancelled=true"));
+
+        // The deprecated `cancelled` parameter still works for cancelled events.
+        let event = Event {
+            status: Status::Cancelled,
+            ..event
+        };
+        let filters = |query_string: &str| Filters {
+            date: DateFilter::All,
+            ..serde_urlencoded::from_str(query_string).unwrap()
+        };
+        assert!(filters("cancelled=true").matches(&event, now));
+        assert!(!filters("cancelled=false").matches(&event, now));
+        assert_eq!(
+            filters("cancelled=true").to_query_string().unwrap(),
+            "date=all&cancelled=true"
+        );
+    }
+
+    #[test]
+    fn match_online() {
+        let mut event: Event = serde_yaml::from_str(
+            r#"
+name: Contra dance
+start_date: 2020-06-28
+end_date: 2020-06-28
+country: USA
+city: Boston
+styles: [contra]
+social: true
+"#,
+        )
+        .unwrap();
+        let now = Utc::now();
+        let matches = |event: &Event, query_string: &str| {
+            Filters {
+                date: DateFilter::All,
+                ..serde_urlencoded::from_str(query_string).unwrap()
+            }
+            .matches(event, now)
+        };
+
+        assert!(!matches(&event, "online=true"));
+        assert!(matches(&event, "online=false"));
+
+        event.attendance = AttendanceMode::Hybrid;
+        assert!(matches(&event, "online=true"));
+        assert!(!matches(&event, "online=false"));
+
+        event.attendance = AttendanceMode::Online;
+        assert!(matches(&event, "online=true"));
+        assert!(!matches(&event, "online=false"));
+    }
+
+    #[test]
+    fn match_inclusivity() {
+        let mut event: Event = serde_yaml::from_str(
+            r#"
+name: Contra dance
+start_date: 2020-06-28
+end_date: 2020-06-28
+country: USA
+city: Boston
+styles: [contra]
+social: true
+level: all
+role_terms: gender_free
+no_partner_needed: true
+accessibility: [step_free, hearing_loop]
+"#,
+        )
+        .unwrap();
+        let now = Utc::now();
+        let matches = |event: &Event, query_string: &str| {
+            Filters {
+                date: DateFilter::All,
+                ..serde_urlencoded::from_str(query_string).unwrap()
+            }
+            .matches(event, now)
+        };
+
+        // An event for all levels suits beginners and advanced dancers too.
+        assert!(matches(&event, "level=beginner"));
+        assert!(matches(&event, "level=advanced"));
+        event.level = Some(Level::Advanced);
+        assert!(!matches(&event, "level=beginner"));
+        event.level = None;
+        assert!(!matches(&event, "level=advanced"));
+
+        assert!(matches(&event, "role_terms=gender_free"));
+        assert!(!matches(&event, "role_terms=gendered"));
+        assert!(matches(&event, "no_partner_needed=true"));
+        assert!(!matches(&event, "no_partner_needed=false"));
+        assert!(!matches(&event, "family_friendly=true"));
+        assert!(matches(&event, "family_friendly=false"));
+        assert!(matches(&event, "accessibility=step_free"));
+        assert!(matches(&event, "accessibility=hearing_loop"));
+        assert!(!matches(&event, "accessibility=accessible_toilet"));
+    }
+
+    #[test]
+    fn match_teacher() {
+        let event: Event = serde_yaml::from_str(
+            r#"
+name: Mazurka workshop
+start_date: 2020-06-28
+end_date: 2020-06-28
+country: Poland
+city: Warsaw
+styles: [polish]
+workshop: true
+teachers: [Janusz Prusinowski]
+"#,
+        )
+        .unwrap();
+        let now = Utc::now();
+        let matches = |query_string: &str| {
+            Filters {
+                date: DateFilter::All,
+                ..serde_urlencoded::from_str(query_string).unwrap()
+            }
+            .matches(&event, now)
+        };
+
+        assert!(matches("teacher=Janusz+Prusinowski"));
+        assert!(!matches("teacher=Someone+else"));
+        assert!(!matches("caller=Janusz+Prusinowski"));
+        assert!(matches("q=prusinowski"));
+    }
+
+    #[test]
+    fn price_title() {
+        assert_eq!(
+            Filters {
+                free: Some(true),
+                ..Default::default()
+            }
+            .make_title(),
+            "Folk dance events free to attend"
+        );
+        assert_eq!(
+            Filters {
+                max_price: Some(10),
+                currency: Some("EUR".to_string()),
+                ..Default::default()
+            }
+            .make_title(),
+            "Folk dance events costing at most 10 EUR"
+        );
+    }
 }
@@ -0,0 +1,166 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use enum_iterator::{Sequence, all};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// An accessibility feature of the venue where an event takes place.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    JsonSchema,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Sequence,
+    Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Accessibility {
+    /// The venue and dance floor can be reached without steps, e.g. by a ramp or lift.
+    StepFree,
+    /// The venue has a wheelchair accessible toilet.
+    AccessibleToilet,
+    /// The venue has a hearing loop.
+    HearingLoop,
+}
+
+impl Accessibility {
+    pub fn values() -> impl Iterator<Item = Self> {
+        all::<Self>()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::StepFree => "step_free",
+            Self::AccessibleToilet => "accessible_toilet",
+            Self::HearingLoop => "hearing_loop",
+        }
+    }
+}
+
+impl Display for Accessibility {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::StepFree => "step-free access",
+            Self::AccessibleToilet => "accessible toilet",
+            Self::HearingLoop => "hearing loop",
+        })
+    }
+}
+
+/// The level of dancing experience an event is aimed at.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    JsonSchema,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Sequence,
+    Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Level {
+    /// Aimed at people who are new to the dance style.
+    Beginner,
+    /// Suitable for everyone, whether they are new to the dance style or experienced.
+    All,
+    /// Aimed at experienced dancers.
+    Advanced,
+}
+
+impl Level {
+    pub fn values() -> impl Iterator<Item = Self> {
+        all::<Self>()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Beginner => "beginner",
+            Self::All => "all",
+            Self::Advanced => "advanced",
+        }
+    }
+
+    /// Returns whether an event at this level is suitable for dancers looking for the given level.
+    pub fn suits(self, wanted: Level) -> bool {
+        self == wanted || self == Self::All
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Beginner => "beginner",
+            Self::All => "all levels",
+            Self::Advanced => "advanced",
+        })
+    }
+}
+
+/// The terms used by the caller or teacher for dancers' roles.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    JsonSchema,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Sequence,
+    Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RoleTerms {
+    /// Gendered terms such as gents and ladies.
+    Gendered,
+    /// Gender-free terms such as larks and robins or leads and follows.
+    GenderFree,
+}
+
+impl RoleTerms {
+    pub fn values() -> impl Iterator<Item = Self> {
+        all::<Self>()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Gendered => "gendered",
+            Self::GenderFree => "gender_free",
+        }
+    }
+}
+
+impl Display for RoleTerms {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Gendered => "gendered roles",
+            Self::GenderFree => "gender-free roles",
+        })
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{dancestyle::DanceStyle, registries::RegistryFile};
+use crate::util::{fold_for_search, slugify};
+use eyre::{Report, bail};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Details about an organisation which runs events.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrganisationDetails {
+    /// The canonical name of the organisation, as used in events.
+    pub name: String,
+    /// Other names or spellings which events may use for the organisation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// The URL of the organisation's website.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    /// An email address to contact the organisation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// The URL of the organisation's logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+    /// The city where the organisation is based.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// The country where the organisation is based.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// The dance styles the organisation runs events for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub styles: Vec<DanceStyle>,
+}
+
+impl OrganisationDetails {
+    /// Returns whether the given name is the name or one of the aliases of this organisation,
+    /// ignoring case and accents.
+    fn has_name(&self, name: &str) -> bool {
+        let name = fold_for_search(name);
+        fold_for_search(&self.name) == name
+            || self
+                .aliases
+                .iter()
+                .any(|alias| fold_for_search(alias) == name)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Organisations {
+    pub organisations: Vec<OrganisationDetails>,
+}
+
+impl Organisations {
+    /// Loads organisations from the given YAML string.
+    ///
+    /// Doesn't validate the organisations.
+    pub fn load_str(s: &str) -> Result<Self, Report> {
+        let organisations = serde_yaml::from_str::<Organisations>(s)?;
+        Ok(organisations)
+    }
+
+    /// Returns the organisation with the given name or alias, if there is one.
+    pub fn get(&self, name: &str) -> Option<&OrganisationDetails> {
+        self.organisations
+            .iter()
+            .find(|organisation| organisation.has_name(name))
+    }
+}
+
+impl RegistryFile for Organisations {
+    const FILENAME: &'static str = "organisations.yaml";
+
+    fn extend(&mut self, other: Self) {
+        self.organisations.extend(other.organisations);
+    }
+
+    fn validate(&self) -> Result<(), Report> {
+        let mut names = HashSet::new();
+        let mut slugs = HashSet::new();
+        for organisation in &self.organisations {
+            if organisation.name.is_empty() {
+                bail!("Organisation must have a name");
+            }
+            if !slugs.insert(slugify(&organisation.name)) {
+                bail!(
+                    "Organisation '{}' has the same URL as another organisation",
+                    organisation.name
+                );
+            }
+            for name in std::iter::once(&organisation.name).chain(&organisation.aliases) {
+                if !names.insert(fold_for_search(name)) {
+                    bail!("Duplicate organisation name or alias '{name}'");
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_by_alias() {
+        let organisations = Organisations::load_str(
+            r#"
+organisations:
+  - name: Folkdance Dunedin
+    aliases: [FDD, Folk Dance Dunedin]
+    website: https://example.com/
+"#,
+        )
+        .unwrap();
+        assert!(organisations.validate().is_ok());
+        assert_eq!(
+            organisations.get("folk dance dunedin").unwrap().name,
+            "Folkdance Dunedin"
+        );
+        assert_eq!(organisations.get("FDD").unwrap().name, "Folkdance Dunedin");
+        assert_eq!(organisations.get("Dunedin"), None);
+    }
+
+    #[test]
+    fn duplicate_aliases() {
+        let organisations = Organisations::load_str(
+            r#"
+organisations:
+  - name: Balfolk A
+    aliases: [Balfolk]
+  - name: Balfolk B
+    aliases: [balfolk]
+"#,
+        )
+        .unwrap();
+        assert!(organisations.validate().is_err());
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::registries::RegistryFile;
+use crate::util::{fold_for_search, slugify};
+use eyre::{Report, bail};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A band or caller who performs at events.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Performer {
+    /// The canonical name of the band or caller, as used in events.
+    pub name: String,
+    /// Other names or spellings by which the band or caller is known.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// The country where the band or caller is based.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// The URL of the band or caller's website.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+}
+
+impl Performer {
+    /// Returns the canonical name followed by any aliases.
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.name).chain(&self.aliases)
+    }
+}
+
+/// Known bands, loaded from `bands.yaml`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(from = "BandsFile")]
+#[schemars(deny_unknown_fields)]
+pub struct Bands {
+    pub bands: Vec<Performer>,
+    /// The index in `bands` of each band by its name and aliases, folded for search.
+    #[serde(skip)]
+    #[schemars(skip)]
+    names: HashMap<String, usize>,
+}
+
+/// The contents of a `bands.yaml` file, from which the index of names is built.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BandsFile {
+    bands: Vec<Performer>,
+}
+
+impl From<BandsFile> for Bands {
+    fn from(file: BandsFile) -> Self {
+        Self::new(file.bands)
+    }
+}
+
+impl Bands {
+    pub fn new(bands: Vec<Performer>) -> Self {
+        let names = index_names(&bands);
+        Self { bands, names }
+    }
+
+    /// Returns the band with the given name or alias, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Performer> {
+        find(&self.bands, &self.names, name)
+    }
+}
+
+impl RegistryFile for Bands {
+    const FILENAME: &'static str = "bands.yaml";
+
+    fn extend(&mut self, other: Self) {
+        self.bands.extend(other.bands);
+        self.names = index_names(&self.bands);
+    }
+
+    fn validate(&self) -> Result<(), Report> {
+        validate(&self.bands, "band")
+    }
+}
+
+/// Known callers, loaded from `callers.yaml`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+#[serde(from = "CallersFile")]
+#[schemars(deny_unknown_fields)]
+pub struct Callers {
+    pub callers: Vec<Performer>,
+    /// The index in `callers` of each caller by their name and aliases, folded for search.
+    #[serde(skip)]
+    #[schemars(skip)]
+    names: HashMap<String, usize>,
+}
+
+/// The contents of a `callers.yaml` file, from which the index of names is built.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CallersFile {
+    callers: Vec<Performer>,
+}
+
+impl From<CallersFile> for Callers {
+    fn from(file: CallersFile) -> Self {
+        Self::new(file.callers)
+    }
+}
+
+impl Callers {
+    pub fn new(callers: Vec<Performer>) -> Self {
+        let names = index_names(&callers);
+        Self { callers, names }
+    }
+
+    /// Returns the caller with the given name or alias, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Performer> {
+        find(&self.callers, &self.names, name)
+    }
+}
+
+impl RegistryFile for Callers {
+    const FILENAME: &'static str = "callers.yaml";
+
+    fn extend(&mut self, other: Self) {
+        self.callers.extend(other.callers);
+        self.names = index_names(&self.callers);
+    }
+
+    fn validate(&self) -> Result<(), Report> {
+        validate(&self.callers, "caller")
+    }
+}
+
+/// Returns the index of each performer by their name and aliases, folded for search so that case
+/// and accents are ignored.
+fn index_names(performers: &[Performer]) -> HashMap<String, usize> {
+    let mut names = HashMap::new();
+    for (index, performer) in performers.iter().enumerate() {
+        for name in performer.names() {
+            names.entry(fold_for_search(name)).or_insert(index);
+        }
+    }
+    names
+}
+
+/// Returns the performer with the given name or alias, ignoring case and accents.
+fn find<'a>(
+    performers: &'a [Performer],
+    names: &HashMap<String, usize>,
+    name: &str,
+) -> Option<&'a Performer> {
+    names
+        .get(&fold_for_search(name))
+        .map(|&index| &performers[index])
+}
+
+fn validate(performers: &[Performer], kind: &str) -> Result<(), Report> {
+    let mut names = HashSet::new();
+    let mut slugs = HashSet::new();
+    for performer in performers {
+        if performer.name.is_empty() {
+            bail!("The name of a {kind} must not be empty");
+        }
+        if !slugs.insert(slugify(&performer.name)) {
+            bail!(
+                "The {kind} '{}' has the same URL as another {kind}",
+                performer.name
+            );
+        }
+        for name in performer.names() {
+            if !names.insert(fold_for_search(name)) {
+                bail!("Duplicate {kind} name or alias '{name}'");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::registries::Registries;
+    use std::path::Path;
+
+    #[test]
+    fn get_by_alias() {
+        // Registry files are added to an empty registry as they are loaded.
+        let mut bands = Bands::default();
+        bands.extend(
+            serde_yaml::from_str(
+                r#"
+bands:
+  - name: Naragonia
+    aliases: [Naragonia Quartet]
+    country: Belgium
+"#,
+            )
+            .unwrap(),
+        );
+        assert!(bands.validate().is_ok());
+        assert_eq!(bands.get("naragonia quartet").unwrap().name, "Naragonia");
+        assert_eq!(bands.get("Naragonia").unwrap().name, "Naragonia");
+        assert_eq!(bands.get("Quartet"), None);
+    }
+
+    #[test]
+    fn deserialised_index() {
+        let callers: Callers = serde_yaml::from_str(
+            r#"
+callers:
+  - name: Alice Smith
+    aliases: [Alice Smyth]
+"#,
+        )
+        .unwrap();
+        assert_eq!(callers.get("alice smyth").unwrap().name, "Alice Smith");
+        assert!(
+            serde_yaml::from_str::<Callers>("callers: []\nother: true\n").is_err(),
+            "Unknown fields should be rejected"
+        );
+    }
+
+    #[test]
+    fn shipped_registries() {
+        let registries = Registries::load_directory(Path::new("registries")).unwrap();
+        assert_eq!(registries.bands.get("naragonia").unwrap().name, "Naragonia");
+        assert!(registries.callers.get("Warren Doyle").is_some());
+    }
+
+    #[test]
+    fn duplicate_names() {
+        let callers: Callers = serde_yaml::from_str(
+            r#"
+callers:
+  - name: Alice Smith
+  - name: Alice Smyth
+    aliases: [alice smith]
+"#,
+        )
+        .unwrap();
+        assert!(callers.validate().is_err());
+    }
+}
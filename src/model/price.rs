@@ -0,0 +1,430 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// Currencies which are written with a symbol before the amount, rather than a code after it.
+const CURRENCY_SYMBOLS: [(&str, &str); 3] = [("EUR", "€"), ("GBP", "£"), ("USD", "$")];
+
+/// The price of an event.
+///
+/// This may be given either as a legacy free-form string such as "€10-€15" or as a structured
+/// map. Strings which are in the canonical format are parsed into the structured fields, anything
+/// else is kept as the note. Prices which can be written in the canonical format are serialised
+/// back as a string, so existing event files don't change.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(from = "PriceRepr", into = "PriceRepr")]
+pub struct Price {
+    /// The minimum price, or the only price if there is no range. This may be a concession price.
+    pub min: Option<f64>,
+    /// The maximum price, if there is a range.
+    pub max: Option<f64>,
+    /// The ISO 4217 code of the currency of the amounts, e.g. "EUR".
+    pub currency: Option<String>,
+    /// The event is free to attend.
+    pub free: bool,
+    /// The event is paid for by donation or "pay what you can".
+    pub donation: bool,
+    /// Any further free-form details about the price.
+    pub note: Option<String>,
+}
+
+impl Price {
+    /// Returns a price for a free event.
+    pub fn free() -> Self {
+        Self {
+            free: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a price for a donation-based event.
+    pub fn donation() -> Self {
+        Self {
+            donation: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a price with the given range in the given currency. If `min` and `max` are equal
+    /// then there is only a single price.
+    pub fn range(min: f64, max: f64, currency: &str) -> Self {
+        Self {
+            min: Some(min),
+            max: (max != min).then_some(max),
+            currency: Some(currency.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    /// Parses a legacy price string.
+    ///
+    /// Strings in the canonical format such as "free", "£5" or "10-15 CHF" are parsed into a
+    /// structured price, anything else is kept as a note.
+    pub fn parse(s: &str) -> Self {
+        let parsed = match s {
+            "free" => Some(Self::free()),
+            "donation" => Some(Self::donation()),
+            _ => parse_amounts(s),
+        };
+        parsed
+            // Only accept the parsed price if it formats back to exactly the same string, so that
+            // nothing is lost.
+            .filter(|price| price.to_string() == s)
+            .unwrap_or_else(|| Self {
+                note: Some(s.to_owned()),
+                ..Default::default()
+            })
+    }
+
+    /// Returns whether the event can be attended without paying, either because it is free or
+    /// because it is donation-based.
+    pub fn is_free(&self) -> bool {
+        self.free || self.donation || self.min == Some(0.0) && self.max.is_none()
+    }
+
+    /// Returns the lowest price to attend the event, if known.
+    pub fn lowest(&self) -> Option<f64> {
+        if self.free || self.donation {
+            Some(0.0)
+        } else {
+            self.min.or(self.max)
+        }
+    }
+
+    /// Returns a list of problems with the price, or an empty list if there are none.
+    pub fn validate(&self) -> Vec<&'static str> {
+        let mut problems = vec![];
+
+        if [self.min, self.max]
+            .into_iter()
+            .flatten()
+            .any(|amount| !amount.is_finite() || amount < 0.0)
+        {
+            problems.push("Price amounts must not be negative.");
+        }
+        if let (Some(min), Some(max)) = (self.min, self.max)
+            && min > max
+        {
+            problems.push("Minimum price must be less than or equal to maximum price.");
+        }
+        if self.min.is_none() && self.max.is_some() {
+            problems.push("Price with a maximum must also have a minimum.");
+        }
+        match &self.currency {
+            Some(currency) => {
+                if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+                    problems.push("Price currency must be a three letter ISO 4217 code.");
+                }
+            }
+            None => {
+                if self.min.is_some() {
+                    problems.push("Price amounts must have a currency.");
+                }
+            }
+        }
+        if self.free && (self.donation || self.min.is_some()) {
+            problems.push("Free events can't have a price or ask for donations.");
+        }
+
+        problems
+    }
+
+    /// Formats the amount or range of amounts, if any, with the currency.
+    fn format_amounts(&self) -> Option<String> {
+        let min = self.min?;
+        let currency = self.currency.as_deref().unwrap_or_default();
+        let symbol = CURRENCY_SYMBOLS
+            .iter()
+            .find(|(code, _)| *code == currency)
+            .map(|(_, symbol)| *symbol);
+        Some(match (symbol, self.max) {
+            (Some(symbol), None) => format!("{symbol}{}", format_amount(min)),
+            (Some(symbol), Some(max)) => format!(
+                "{symbol}{}-{symbol}{}",
+                format_amount(min),
+                format_amount(max)
+            ),
+            (None, None) => format!("{} {currency}", format_amount(min)),
+            (None, Some(max)) => {
+                format!("{}-{} {currency}", format_amount(min), format_amount(max))
+            }
+        })
+    }
+}
+
+impl Display for Price {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let amounts = self.format_amounts();
+        let main = if self.free {
+            Some("free".to_owned())
+        } else if self.donation {
+            Some(match amounts {
+                Some(amounts) => format!("donation ({amounts} suggested)"),
+                None => "donation".to_owned(),
+            })
+        } else {
+            amounts
+        };
+        match (main, &self.note) {
+            (Some(main), Some(note)) => write!(f, "{main}, {note}"),
+            (Some(main), None) => f.write_str(&main),
+            (None, Some(note)) => f.write_str(note),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl From<&str> for Price {
+    fn from(s: &str) -> Self {
+        Self::parse(s)
+    }
+}
+
+impl From<String> for Price {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+/// Formats an amount of money, with two decimal places only if it is not a whole number.
+fn format_amount(amount: f64) -> String {
+    if amount.fract() == 0.0 {
+        format!("{amount:.0}")
+    } else {
+        format!("{amount:.2}")
+    }
+}
+
+/// Parses an amount or range of amounts with either a currency symbol before each amount or a
+/// currency code after them, like "€10-€15" or "10-15 CHF".
+fn parse_amounts(s: &str) -> Option<Price> {
+    let (amounts, currency) = if let Some((code, symbol)) = CURRENCY_SYMBOLS
+        .iter()
+        .find(|(_, symbol)| s.starts_with(symbol))
+    {
+        (s.replace(symbol, ""), (*code).to_owned())
+    } else {
+        let (amounts, code) = s.rsplit_once(' ')?;
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_uppercase()) {
+            return None;
+        }
+        (amounts.to_owned(), code.to_owned())
+    };
+    let parse_amount = |amount: &str| {
+        amount
+            .parse::<f64>()
+            .ok()
+            .filter(|amount| amount.is_finite() && *amount >= 0.0)
+    };
+    let (min, max) = match amounts.split_once('-') {
+        Some((min, max)) => (parse_amount(min)?, parse_amount(max)?),
+        None => {
+            let amount = parse_amount(&amounts)?;
+            (amount, amount)
+        }
+    };
+    Some(Price::range(min, max, &currency))
+}
+
+/// The serialised form of a price, either a legacy string or a map of the structured fields.
+#[derive(Deserialize, JsonSchema, Serialize)]
+#[serde(untagged)]
+pub enum PriceRepr {
+    Text(String),
+    Structured {
+        /// The minimum price, or the only price if there is no range. This may be a concession
+        /// price.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<f64>,
+        /// The maximum price, if there is a range.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<f64>,
+        /// The ISO 4217 code of the currency of the amounts, e.g. "EUR".
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        currency: Option<String>,
+        /// The event is free to attend.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        free: bool,
+        /// The event is paid for by donation or "pay what you can".
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        donation: bool,
+        /// Any further free-form details about the price.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    },
+}
+
+impl From<PriceRepr> for Price {
+    fn from(repr: PriceRepr) -> Self {
+        match repr {
+            PriceRepr::Text(s) => Self::parse(&s),
+            PriceRepr::Structured {
+                min,
+                max,
+                currency,
+                free,
+                donation,
+                note,
+            } => Self {
+                min,
+                max,
+                currency,
+                free,
+                donation,
+                note,
+            },
+        }
+    }
+}
+
+impl From<Price> for PriceRepr {
+    fn from(price: Price) -> Self {
+        let s = price.to_string();
+        if Price::parse(&s) == price {
+            Self::Text(s)
+        } else {
+            Self::Structured {
+                min: price.min,
+                max: price.max,
+                currency: price.currency,
+                free: price.free,
+                donation: price.donation,
+                note: price.note,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_canonical() {
+        assert_eq!(Price::parse("free"), Price::free());
+        assert_eq!(Price::parse("donation"), Price::donation());
+        assert_eq!(Price::parse("£5"), Price::range(5.0, 5.0, "GBP"));
+        assert_eq!(Price::parse("€10-€15"), Price::range(10.0, 15.0, "EUR"));
+        assert_eq!(Price::parse("$7.50"), Price::range(7.5, 7.5, "USD"));
+        assert_eq!(Price::parse("90 PLN"), Price::range(90.0, 90.0, "PLN"));
+        assert_eq!(Price::parse("10-15 CHF"), Price::range(10.0, 15.0, "CHF"));
+    }
+
+    #[test]
+    fn parse_other() {
+        for s in [
+            "€ 10",
+            "$5 suggested",
+            "Free",
+            "$10-$20 sliding scale",
+            "€7.5",
+            "5 quid",
+            "NaN CHF",
+            "",
+        ] {
+            assert_eq!(
+                Price::parse(s),
+                Price {
+                    note: Some(s.to_owned()),
+                    ..Default::default()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Price {
+                note: Some("concessions €8".to_owned()),
+                ..Price::range(10.0, 12.0, "EUR")
+            }
+            .to_string(),
+            "€10-€12, concessions €8"
+        );
+        assert_eq!(
+            Price {
+                donation: true,
+                ..Price::range(5.0, 10.0, "GBP")
+            }
+            .to_string(),
+            "donation (£5-£10 suggested)"
+        );
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        for s in ["free", "€10-€15", "10 CHF", "£5 on the door"] {
+            let yaml = serde_yaml::to_string(&s).unwrap();
+            let price: Price = serde_yaml::from_str(&yaml).unwrap();
+            assert_eq!(serde_yaml::to_string(&price).unwrap(), yaml);
+        }
+    }
+
+    #[test]
+    fn deserialize_structured() {
+        let price: Price =
+            serde_yaml::from_str("min: 8\nmax: 12\ncurrency: GBP\nnote: under 18s free\n").unwrap();
+        assert_eq!(
+            price,
+            Price {
+                note: Some("under 18s free".to_owned()),
+                ..Price::range(8.0, 12.0, "GBP")
+            }
+        );
+        assert_eq!(
+            serde_yaml::to_string(&price).unwrap(),
+            "---\nmin: 8.0\nmax: 12.0\ncurrency: GBP\nnote: under 18s free\n"
+        );
+
+        // Structured prices which can be written as a canonical string are serialised as one.
+        let price: Price = serde_yaml::from_str("min: 8\ncurrency: GBP\n").unwrap();
+        assert_eq!(serde_yaml::to_string(&price).unwrap(), "---\n£8\n");
+    }
+
+    #[test]
+    fn free_and_lowest() {
+        assert!(Price::free().is_free());
+        assert!(Price::donation().is_free());
+        assert!(Price::range(0.0, 0.0, "EUR").is_free());
+        assert!(!Price::range(0.0, 10.0, "EUR").is_free());
+        assert_eq!(Price::range(5.0, 10.0, "EUR").lowest(), Some(5.0));
+        assert_eq!(Price::donation().lowest(), Some(0.0));
+        assert_eq!(Price::parse("ask at the door").lowest(), None);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(Price::range(5.0, 10.0, "EUR").validate().is_empty());
+        assert!(Price::parse("anything").validate().is_empty());
+        assert_eq!(
+            Price::range(10.0, 5.0, "euro").validate(),
+            vec![
+                "Minimum price must be less than or equal to maximum price.",
+                "Price currency must be a three letter ISO 4217 code.",
+            ]
+        );
+        assert_eq!(
+            Price {
+                free: true,
+                ..Price::range(5.0, 5.0, "EUR")
+            }
+            .validate(),
+            vec!["Free events can't have a price or ask for donations."]
+        );
+    }
+}
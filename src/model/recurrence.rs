@@ -0,0 +1,502 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    event::{Event, EventTime},
+    price::{Price, PriceRepr},
+    session::Session,
+    status::Status,
+};
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeDelta, TimeZone, Utc};
+use eyre::{Report, eyre};
+use rrule::{RRule, RRuleSet, Unvalidated};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, sync::Arc};
+
+/// How many days before the current date recurring events are expanded from, by default.
+const DEFAULT_PAST_DAYS: u32 = 365;
+/// How many days after the current date recurring events are expanded until, by default.
+const DEFAULT_FUTURE_DAYS: u32 = 365;
+
+/// A rule for repeating an event, starting from the instance given by the event's own time.
+#[derive(Clone, Debug, Deserialize, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Recurrence {
+    /// An iCalendar recurrence rule, without the `RRULE:` prefix, e.g. `FREQ=WEEKLY;BYDAY=TU` or
+    /// `FREQ=MONTHLY;BYDAY=1SA;UNTIL=20271231T000000Z`. `UNTIL` must be given in UTC.
+    pub rrule: String,
+    /// The dates of instances which don't take place.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub except: Vec<NaiveDate>,
+    /// Changes to the details of particular instances.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<InstanceOverride>,
+}
+
+/// Changes to the details of a single instance of a recurring event. Any fields which are not given
+/// are the same as for the rest of the series.
+#[derive(Clone, Debug, Deserialize, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstanceOverride {
+    /// The date on which the instance starts.
+    pub date: NaiveDate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bands: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callers: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub teachers: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<PriceRepr>")]
+    pub price: Option<Price>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+impl InstanceOverride {
+    fn apply_to(&self, event: &mut Event) {
+        if let Some(name) = &self.name {
+            event.name.clone_from(name);
+        }
+        if let Some(details) = &self.details {
+            event.details = Some(details.clone());
+        }
+        if let Some(bands) = &self.bands {
+            event.bands.clone_from(bands);
+        }
+        if let Some(callers) = &self.callers {
+            event.callers.clone_from(callers);
+        }
+        if let Some(teachers) = &self.teachers {
+            event.teachers.clone_from(teachers);
+        }
+        if let Some(price) = &self.price {
+            event.price = Some(price.clone());
+        }
+        if let Some(status) = self.status {
+            event.status = status;
+        }
+    }
+}
+
+/// How far around the current date to expand recurring events into individual events.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RecurrenceWindow {
+    /// How many days before the current date to include instances from.
+    #[serde(default = "default_past_days")]
+    pub past_days: u32,
+    /// How many days after the current date to include instances until.
+    #[serde(default = "default_future_days")]
+    pub future_days: u32,
+}
+
+impl Default for RecurrenceWindow {
+    fn default() -> Self {
+        Self {
+            past_days: DEFAULT_PAST_DAYS,
+            future_days: DEFAULT_FUTURE_DAYS,
+        }
+    }
+}
+
+impl RecurrenceWindow {
+    /// Returns the first and last instants of the window around the given time.
+    fn range(self, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            now - TimeDelta::days(self.past_days.into()),
+            now + TimeDelta::days(self.future_days.into()),
+        )
+    }
+}
+
+fn default_past_days() -> u32 {
+    DEFAULT_PAST_DAYS
+}
+
+fn default_future_days() -> u32 {
+    DEFAULT_FUTURE_DAYS
+}
+
+impl Recurrence {
+    /// Checks that the recurrence is valid for the given event. Returns an empty list if it is, or a
+    /// list of problems if not.
+    pub fn validate(&self, event: &Event) -> Vec<&'static str> {
+        let mut problems = vec![];
+        let has_time = matches!(event.time, EventTime::DateTime { .. })
+            || event
+                .sessions
+                .iter()
+                .any(|session| matches!(session.time, EventTime::DateTime { .. }));
+        if has_time && event.timezone.is_none() {
+            problems.push("Recurring events with a time must specify a timezone.");
+        } else if self.rule_set(event).is_err() {
+            problems.push("Invalid recurrence rule.");
+        }
+        let mut override_dates = HashSet::new();
+        if !self
+            .overrides
+            .iter()
+            .all(|instance_override| override_dates.insert(instance_override.date))
+        {
+            problems.push("Recurrence overrides must be for different dates.");
+        }
+        problems
+    }
+
+    /// Builds the set of instance start times for the given event, which this recurrence belongs to.
+    fn rule_set(&self, event: &Event) -> Result<RRuleSet, Report> {
+        let rrule: RRule<Unvalidated> = self.rrule.parse()?;
+        let dt_start = match event.time {
+            EventTime::DateOnly { start_date, .. } => {
+                rrule::Tz::UTC.from_utc_datetime(&start_date.and_hms_opt(0, 0, 0).unwrap())
+            }
+            EventTime::DateTime { start, .. } => {
+                let timezone = event
+                    .timezone
+                    .ok_or_else(|| eyre!("Recurring event '{}' has no timezone", event.name))?;
+                start.with_timezone(&rrule::Tz::Tz(timezone))
+            }
+        };
+        Ok(rrule.build(dt_start)?)
+    }
+}
+
+/// Expands the given recurring event into an event for each of its instances which overlaps the
+/// window around the given time, skipping exceptions and applying any overrides.
+///
+/// Events which don't recur are returned unchanged.
+pub fn expand(
+    event: Event,
+    window: RecurrenceWindow,
+    now: DateTime<Utc>,
+) -> Result<Vec<Event>, Report> {
+    let Some(recurrence) = &event.recurrence else {
+        return Ok(vec![event]);
+    };
+    let (window_start, window_end) = window.range(now);
+    let rule_set = recurrence.rule_set(&event)?;
+    let series = Arc::new(event.clone());
+    let mut instances = vec![];
+    for instance_start in &rule_set {
+        let date = instance_start.date_naive();
+        if recurrence.except.contains(&date) {
+            continue;
+        }
+        let Some(instance) = instance(&series, date) else {
+            continue;
+        };
+        if instance.time.start_time_sort_key() > window_end {
+            break;
+        }
+        if instance.time.end_time_sort_key() >= window_start {
+            instances.push(instance);
+        }
+    }
+    Ok(instances)
+}
+
+/// Returns the instance of the given recurring event which starts on the given date, with any
+/// override for that date applied.
+///
+/// This doesn't check whether the recurrence rule actually has an instance on that date. Returns
+/// `None` if the event's start time doesn't exist on that date in its timezone.
+pub fn instance(series: &Arc<Event>, date: NaiveDate) -> Option<Event> {
+    let mut instance = Event {
+        // Derive the instance's ID from the series so that it is the same every time it is expanded.
+        id: Some(format!("{}-{date}", series.id_or_hash())),
+        time: instance_time(series, date)?,
+        sessions: series
+            .sessions
+            .iter()
+            .map(|session| {
+                Some(Session {
+                    time: shift_time(&session.time, series, date)?,
+                    ..session.clone()
+                })
+            })
+            .collect::<Option<_>>()?,
+        recurrence: None,
+        series: Some(series.clone()),
+        ..Event::clone(series)
+    };
+    if let Some(instance_override) = series
+        .recurrence
+        .iter()
+        .flat_map(|recurrence| &recurrence.overrides)
+        .find(|instance_override| instance_override.date == date)
+    {
+        instance_override.apply_to(&mut instance);
+    }
+    Some(instance)
+}
+
+/// Returns the time of the instance of the given recurring event which starts on the given date,
+/// at the same local time and with the same duration as the first instance.
+pub fn instance_time(series: &Event, date: NaiveDate) -> Option<EventTime> {
+    match series.time {
+        EventTime::DateOnly {
+            start_date,
+            end_date,
+        } => Some(EventTime::DateOnly {
+            start_date: date,
+            end_date: date + (end_date - start_date),
+        }),
+        EventTime::DateTime { start, end } => {
+            let timezone = series.timezone?;
+            let local_start = date.and_time(start.with_timezone(&timezone).time());
+            let instance_start = timezone.from_local_datetime(&local_start).earliest()?;
+            Some(EventTime::DateTime {
+                start: instance_start.fixed_offset(),
+                end: (instance_start + (end - start)).fixed_offset(),
+            })
+        }
+    }
+}
+
+/// Moves the given time, such as that of a session within the first instance of the given recurring
+/// event, to the same local time relative to the instance which starts on the given date.
+fn shift_time(time: &EventTime, series: &Event, date: NaiveDate) -> Option<EventTime> {
+    let days = date - series.time.start_date();
+    match *time {
+        EventTime::DateOnly {
+            start_date,
+            end_date,
+        } => Some(EventTime::DateOnly {
+            start_date: start_date + days,
+            end_date: end_date + days,
+        }),
+        EventTime::DateTime { start, end } => {
+            let timezone = series.timezone?;
+            let shift = |time: DateTime<FixedOffset>| {
+                let local_time = time.with_timezone(&timezone).naive_local() + days;
+                Some(
+                    timezone
+                        .from_local_datetime(&local_time)
+                        .earliest()?
+                        .fixed_offset(),
+                )
+            };
+            Some(EventTime::DateTime {
+                start: shift(start)?,
+                end: shift(end)?,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+
+    fn weekly_series() -> Event {
+        serde_yaml::from_str(
+            r#"
+id: weekly
+name: Tuesday balfolk
+start: 2026-10-06T19:30:00+02:00
+end: 2026-10-06T22:00:00+02:00
+timezone: Europe/Brussels
+country: Belgium
+city: Gent
+styles: [balfolk]
+social: true
+recurrence:
+  rrule: FREQ=WEEKLY;BYDAY=TU;COUNT=6
+  except: [2026-10-20]
+  overrides:
+    - date: 2026-11-03
+      name: Tuesday balfolk with live music
+      bands: [Naragonia]
+"#,
+        )
+        .unwrap()
+    }
+
+    fn start_times(events: &[Event]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event.time {
+                EventTime::DateTime { start, .. } => start.to_rfc3339(),
+                EventTime::DateOnly { start_date, .. } => start_date.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn expand_weekly() {
+        let now = NaiveDateTime::parse_from_str("2026-10-01 12:00", "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc();
+        let instances = expand(weekly_series(), RecurrenceWindow::default(), now).unwrap();
+
+        // The local time stays the same when summer time ends, and the exception is skipped.
+        assert_eq!(
+            start_times(&instances),
+            vec![
+                "2026-10-06T19:30:00+02:00",
+                "2026-10-13T19:30:00+02:00",
+                "2026-10-27T19:30:00+01:00",
+                "2026-11-03T19:30:00+01:00",
+                "2026-11-10T19:30:00+01:00",
+            ]
+        );
+        assert_eq!(instances[2].id.as_deref(), Some("weekly-2026-10-27"));
+        assert_eq!(instances[2].recurrence, None);
+        assert_eq!(
+            instances[2].series.as_ref().unwrap().id.as_deref(),
+            Some("weekly")
+        );
+        assert_eq!(instances[2].name, "Tuesday balfolk");
+        assert_eq!(instances[3].name, "Tuesday balfolk with live music");
+        assert_eq!(instances[3].bands, vec!["Naragonia"]);
+
+        // Without an ID, instance IDs come from the hash of the series, so they are still the same
+        // each time it is expanded.
+        let series = Event {
+            id: None,
+            ..weekly_series()
+        };
+        let hash = series.hash_string();
+        let instances = expand(series.clone(), RecurrenceWindow::default(), now).unwrap();
+        assert_eq!(instances[0].id, Some(format!("{hash}-2026-10-06")));
+        assert_eq!(
+            expand(series, RecurrenceWindow::default(), now).unwrap(),
+            instances
+        );
+    }
+
+    #[test]
+    fn expand_window() {
+        let now = NaiveDateTime::parse_from_str("2026-11-01 12:00", "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc();
+        let window = RecurrenceWindow {
+            past_days: 7,
+            future_days: 7,
+        };
+        let instances = expand(weekly_series(), window, now).unwrap();
+        assert_eq!(
+            start_times(&instances),
+            vec!["2026-10-27T19:30:00+01:00", "2026-11-03T19:30:00+01:00"]
+        );
+    }
+
+    #[test]
+    fn expand_date_only_unbounded() {
+        let event: Event = serde_yaml::from_str(
+            r#"
+name: Monthly festival weekend
+start_date: 2026-01-03
+end_date: 2026-01-04
+country: UK
+city: London
+styles: [contra]
+social: true
+recurrence:
+  rrule: FREQ=MONTHLY;BYDAY=1SA
+"#,
+        )
+        .unwrap();
+        let now = NaiveDateTime::parse_from_str("2026-03-15 12:00", "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc();
+        let window = RecurrenceWindow {
+            past_days: 30,
+            future_days: 60,
+        };
+        let instances = expand(event, window, now).unwrap();
+        assert_eq!(
+            start_times(&instances),
+            vec!["2026-03-07", "2026-04-04", "2026-05-02"]
+        );
+        assert_eq!(
+            instances[0].time,
+            EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2026, 3, 7).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 3, 8).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn expand_sessions() {
+        let event: Event = serde_yaml::from_str(
+            r#"
+name: Tuesday balfolk
+start: 2026-10-20T19:00:00+02:00
+end: 2026-10-20T23:00:00+02:00
+timezone: Europe/Brussels
+country: Belgium
+city: Gent
+styles: [balfolk]
+sessions:
+  - kind: workshop
+    start: 2026-10-20T19:00:00+02:00
+    end: 2026-10-20T20:30:00+02:00
+  - kind: social
+    start: 2026-10-20T20:30:00+02:00
+    end: 2026-10-20T23:00:00+02:00
+recurrence:
+  rrule: FREQ=WEEKLY;COUNT=2
+"#,
+        )
+        .unwrap();
+        let now = NaiveDateTime::parse_from_str("2026-10-01 12:00", "%Y-%m-%d %H:%M")
+            .unwrap()
+            .and_utc();
+        let instances = expand(event, RecurrenceWindow::default(), now).unwrap();
+
+        // Sessions keep their local time after summer time ends.
+        let session_times = instances[1]
+            .sessions
+            .iter()
+            .map(|session| session.time.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            session_times,
+            vec![
+                EventTime::DateTime {
+                    start: "2026-10-27T19:00:00+01:00".parse().unwrap(),
+                    end: "2026-10-27T20:30:00+01:00".parse().unwrap(),
+                },
+                EventTime::DateTime {
+                    start: "2026-10-27T20:30:00+01:00".parse().unwrap(),
+                    end: "2026-10-27T23:00:00+01:00".parse().unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let mut event = weekly_series();
+        assert_eq!(event.validate(), Vec::<&str>::new());
+
+        event.recurrence.as_mut().unwrap().rrule = "FREQ=SOMETIMES".to_string();
+        assert_eq!(event.validate(), vec!["Invalid recurrence rule."]);
+
+        event.timezone = None;
+        assert_eq!(
+            event.validate(),
+            vec!["Recurring events with a time must specify a timezone."]
+        );
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    organisations::Organisations,
+    performers::{Bands, Callers},
+    venues::Venues,
+};
+use eyre::{Report, WrapErr};
+use log::trace;
+use serde::de::DeserializeOwned;
+use std::{
+    ffi::OsStr,
+    fs::{read_dir, read_to_string},
+    path::Path,
+};
+
+/// A kind of file which holds shared details that events can refer to, rather than events.
+pub trait RegistryFile: DeserializeOwned + Default {
+    /// The name of files of this kind.
+    const FILENAME: &'static str;
+
+    /// Adds the entries from another file to this one.
+    fn extend(&mut self, other: Self);
+
+    /// Checks that the entries are valid and consistent with each other.
+    fn validate(&self) -> Result<(), Report>;
+}
+
+/// The names of all kinds of registry file.
+const REGISTRY_FILENAMES: [&str; 4] = [
+    Venues::FILENAME,
+    Organisations::FILENAME,
+    Bands::FILENAME,
+    Callers::FILENAME,
+];
+
+/// The name of the directory which holds all events. Registry files above it aren't used.
+const EVENTS_DIRECTORY: &str = "events";
+
+/// All the registry files for a set of events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Registries {
+    pub venues: Venues,
+    pub organisations: Organisations,
+    pub bands: Bands,
+    pub callers: Callers,
+}
+
+impl Registries {
+    /// Loads registries for the given file or directory of events, or none for a URL.
+    pub fn load_for_path(path_or_url: &str) -> Result<Self, Report> {
+        if path_or_url.starts_with("http://") || path_or_url.starts_with("https://") {
+            return Ok(Self::default());
+        }
+        let path = Path::new(path_or_url);
+        if path.is_dir() {
+            Self::load_directory(path)
+        } else {
+            Self::load_for_file(path)
+        }
+    }
+
+    /// Loads registries from all registry files in the given directory and its subdirectories.
+    ///
+    /// This is everything known for the site. Events may only refer to the entries for their own
+    /// directory, from [`Self::extended_for_directory`].
+    pub fn load_directory(directory: &Path) -> Result<Self, Report> {
+        let mut registries = Self::default();
+        registries.read_directory(directory)?;
+        registries.validate()?;
+        Ok(registries)
+    }
+
+    /// Loads registries from any registry files in the directory containing the given events file
+    /// or its parent directories, up to the closest one named `events`. If there is no such
+    /// directory then only the directory containing the file is used.
+    pub fn load_for_file(filename: &Path) -> Result<Self, Report> {
+        let directory = filename.parent().unwrap_or(Path::new(""));
+        let root = directory
+            .ancestors()
+            .find(|ancestor| ancestor.file_name() == Some(OsStr::new(EVENTS_DIRECTORY)))
+            .unwrap_or(directory);
+        let mut directories = directory
+            .ancestors()
+            .take_while(|ancestor| *ancestor != root)
+            .collect::<Vec<_>>();
+        directories.push(root);
+
+        let mut registries = Self::default();
+        for directory in directories.into_iter().rev() {
+            registries = registries.extended_for_directory(directory)?;
+        }
+        Ok(registries)
+    }
+
+    /// Returns these registries with the entries from any registry files directly in the given
+    /// directory added.
+    ///
+    /// Starting from the root of the events, this gives the entries which events in the directory
+    /// may refer to: those in the same directory or any of its parents, but not its siblings.
+    pub fn extended_for_directory(&self, directory: &Path) -> Result<Self, Report> {
+        let mut registries = self.clone();
+        for registry_filename in REGISTRY_FILENAMES {
+            let registry_filename = directory.join(registry_filename);
+            if registry_filename.is_file() {
+                registries.read_file(&registry_filename)?;
+            }
+        }
+        registries.validate()?;
+        Ok(registries)
+    }
+
+    fn read_directory(&mut self, directory: &Path) -> Result<(), Report> {
+        for entry in read_dir(directory)? {
+            let filename = entry?.path();
+            if filename.is_dir() {
+                self.read_directory(&filename)?;
+            } else if is_registry_file(&filename) {
+                self.read_file(&filename)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_file(&mut self, filename: &Path) -> Result<(), Report> {
+        match filename.file_name().and_then(|name| name.to_str()) {
+            Some(Venues::FILENAME) => self.venues.extend(read_registry_file(filename)?),
+            Some(Organisations::FILENAME) => {
+                self.organisations.extend(read_registry_file(filename)?)
+            }
+            Some(Bands::FILENAME) => self.bands.extend(read_registry_file(filename)?),
+            Some(Callers::FILENAME) => self.callers.extend(read_registry_file(filename)?),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), Report> {
+        self.venues.validate()?;
+        self.organisations.validate()?;
+        self.bands.validate()?;
+        self.callers.validate()?;
+        Ok(())
+    }
+}
+
+/// Returns whether the given filename is a registry file rather than a file of events.
+pub fn is_registry_file(filename: &Path) -> bool {
+    filename
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| REGISTRY_FILENAMES.contains(&name))
+}
+
+fn read_registry_file<T: RegistryFile>(filename: &Path) -> Result<T, Report> {
+    trace!("Reading {} from {filename:?}", T::FILENAME);
+    let contents = read_to_string(filename).wrap_err_with(|| format!("Reading {filename:?}"))?;
+    serde_yaml::from_str(&contents).wrap_err_with(|| format!("Reading {filename:?}"))
+}
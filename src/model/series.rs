@@ -0,0 +1,370 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::event::{Event, EventTime};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+/// The minimum number of events needed to detect a series.
+const MIN_SERIES_LENGTH: usize = 3;
+
+/// Events which appear to be regular instances of the same series, because they have the same
+/// name, organisation, location and start time, with a regular interval between them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    pub regularity: Regularity,
+    /// The local start time of each instance, if they have one.
+    pub start_time: Option<NaiveTime>,
+    /// The instances, in the order they were given.
+    pub events: Vec<Event>,
+}
+
+impl Series {
+    /// Returns a description of when the series takes place, e.g. "Every Tuesday, 7:30 pm".
+    pub fn description(&self) -> String {
+        if let Some(start_time) = self.start_time {
+            format!("{}, {}", self.regularity, start_time.format("%-I:%M %P"))
+        } else {
+            self.regularity.to_string()
+        }
+    }
+}
+
+/// How often the instances of a series take place.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Regularity {
+    /// On the same weekday every given number of weeks.
+    Weekly { weeks: u32, weekday: Weekday },
+    /// On the given occurrence of a weekday in every month, e.g. the 2nd Friday.
+    Monthly { week: u32, weekday: Weekday },
+}
+
+impl Regularity {
+    /// Works out the regularity of the given dates, in ascending order, if they follow one.
+    ///
+    /// Some instances may be missing, e.g. for a summer break, so long as the remaining dates still
+    /// follow the pattern.
+    fn detect(dates: &[NaiveDate]) -> Option<Self> {
+        let weekday = dates.first()?.weekday();
+        if dates.iter().any(|date| date.weekday() != weekday) {
+            return None;
+        }
+        let gaps = dates
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).num_days())
+            .collect::<Vec<_>>();
+        let min_gap = *gaps.iter().min()?;
+        if min_gap <= 0 {
+            return None;
+        }
+        if (min_gap == 7 || min_gap == 14) && gaps.iter().all(|gap| gap % min_gap == 0) {
+            return Some(Self::Weekly {
+                weeks: (min_gap / 7) as u32,
+                weekday,
+            });
+        }
+        let week = week_of_month(dates[0]);
+        if dates.iter().all(|date| week_of_month(*date) == week) {
+            Some(Self::Monthly { week, weekday })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Regularity {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Weekly { weeks: 1, weekday } => write!(f, "Every {}", weekday_name(*weekday)),
+            Self::Weekly { weeks: 2, weekday } => {
+                write!(f, "Every other {}", weekday_name(*weekday))
+            }
+            Self::Weekly { weeks, weekday } => {
+                write!(f, "Every {weeks} weeks on {}", weekday_name(*weekday))
+            }
+            Self::Monthly { week, weekday } => write!(
+                f,
+                "Every {}{} {} of the month",
+                week,
+                ordinal_suffix(*week),
+                weekday_name(*weekday)
+            ),
+        }
+    }
+}
+
+/// Groups regular instances of the same series together.
+///
+/// Returns the events in the order given, except that each series is replaced by its first
+/// instance paired with the whole series. Cancelled and postponed events are never grouped, so
+/// they stay visible.
+pub fn collapse(events: Vec<Event>) -> Vec<(Event, Option<Series>)> {
+    let mut groups: HashMap<_, Vec<usize>> = HashMap::new();
+    for (index, event) in events.iter().enumerate() {
+        if !event.multiday() && !event.status.is_called_off() {
+            groups.entry(SeriesKey::new(event)).or_default().push(index);
+        }
+    }
+
+    // The index of the first instance of each series, along with the series.
+    let mut series = HashMap::new();
+    let mut grouped = vec![false; events.len()];
+    for indices in groups.into_values() {
+        if indices.len() < MIN_SERIES_LENGTH {
+            continue;
+        }
+        let mut dates = indices
+            .iter()
+            .map(|&index| events[index].time.start_date())
+            .collect::<Vec<_>>();
+        dates.sort();
+        if let Some(regularity) = Regularity::detect(&dates) {
+            for &index in &indices {
+                grouped[index] = true;
+            }
+            series.insert(
+                indices[0],
+                Series {
+                    regularity,
+                    start_time: start_time(&events[indices[0]]),
+                    events: indices.iter().map(|&index| events[index].clone()).collect(),
+                },
+            );
+        }
+    }
+
+    events
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, event)| {
+            if let Some(series) = series.remove(&index) {
+                Some((event, Some(series)))
+            } else if grouped[index] {
+                None
+            } else {
+                Some((event, None))
+            }
+        })
+        .collect()
+}
+
+/// The details which all instances of a series must have in common.
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct SeriesKey<'a> {
+    name: &'a str,
+    organisation: Option<&'a str>,
+    country: &'a str,
+    state: Option<&'a str>,
+    city: &'a str,
+    start_time: Option<NaiveTime>,
+}
+
+impl<'a> SeriesKey<'a> {
+    fn new(event: &'a Event) -> Self {
+        Self {
+            name: &event.name,
+            organisation: event.organisation.as_deref(),
+            country: &event.country,
+            state: event.state.as_deref(),
+            city: &event.city,
+            start_time: start_time(event),
+        }
+    }
+}
+
+/// Returns the local start time of the event, if it has one.
+fn start_time(event: &Event) -> Option<NaiveTime> {
+    match event.time {
+        EventTime::DateOnly { .. } => None,
+        EventTime::DateTime { start, .. } => Some(start.time()),
+    }
+}
+
+/// Returns which occurrence of its weekday the date is within its month, starting from 1.
+fn week_of_month(date: NaiveDate) -> u32 {
+    (date.day() - 1) / 7 + 1
+}
+
+fn ordinal_suffix(number: u32) -> &'static str {
+    match number {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    }
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::events::Events;
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn detect_regularity() {
+        assert_eq!(
+            Regularity::detect(&[date("2026-10-06"), date("2026-10-13"), date("2026-10-27")]),
+            Some(Regularity::Weekly {
+                weeks: 1,
+                weekday: Weekday::Tue
+            })
+        );
+        assert_eq!(
+            Regularity::detect(&[date("2026-10-02"), date("2026-10-16"), date("2026-10-30")]),
+            Some(Regularity::Weekly {
+                weeks: 2,
+                weekday: Weekday::Fri
+            })
+        );
+        assert_eq!(
+            Regularity::detect(&[date("2026-10-09"), date("2026-11-13"), date("2026-12-11")]),
+            Some(Regularity::Monthly {
+                week: 2,
+                weekday: Weekday::Fri
+            })
+        );
+        // Different weekdays.
+        assert_eq!(
+            Regularity::detect(&[date("2026-10-06"), date("2026-10-14"), date("2026-10-20")]),
+            None
+        );
+        // Same weekday but no pattern.
+        assert_eq!(
+            Regularity::detect(&[date("2026-10-06"), date("2026-10-20"), date("2026-11-24")]),
+            None
+        );
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            Regularity::Weekly {
+                weeks: 2,
+                weekday: Weekday::Sun
+            }
+            .to_string(),
+            "Every other Sunday"
+        );
+        assert_eq!(
+            Regularity::Monthly {
+                week: 1,
+                weekday: Weekday::Sat
+            }
+            .to_string(),
+            "Every 1st Saturday of the month"
+        );
+    }
+
+    #[test]
+    fn collapse_weekly() {
+        let events = Events::load_str(
+            r#"
+events:
+  - name: Tuesday balfolk
+    start: 2026-10-06T19:30:00+02:00
+    end: 2026-10-06T22:00:00+02:00
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+  - name: Festival
+    start_date: 2026-10-10
+    end_date: 2026-10-11
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+  - name: Tuesday balfolk
+    start: 2026-10-13T19:30:00+02:00
+    end: 2026-10-13T22:00:00+02:00
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+  - name: Tuesday balfolk
+    start: 2026-10-20T19:30:00+02:00
+    end: 2026-10-20T22:00:00+02:00
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+    status: cancelled
+  - name: Tuesday balfolk
+    start: 2026-10-27T19:30:00+01:00
+    end: 2026-10-27T22:00:00+01:00
+    country: Belgium
+    city: Gent
+    styles: [balfolk]
+    social: true
+"#,
+        )
+        .unwrap();
+
+        let collapsed = collapse(events.events);
+        let summary = collapsed
+            .iter()
+            .map(|(event, series)| {
+                (
+                    event.time.start_date().to_string(),
+                    series.as_ref().map(|series| {
+                        (
+                            series.description(),
+                            series
+                                .events
+                                .iter()
+                                .map(|event| event.time.start_date().to_string())
+                                .collect::<Vec<_>>(),
+                        )
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "2026-10-06".to_string(),
+                    Some((
+                        "Every Tuesday, 7:30 pm".to_string(),
+                        vec![
+                            "2026-10-06".to_string(),
+                            "2026-10-13".to_string(),
+                            "2026-10-27".to_string()
+                        ]
+                    ))
+                ),
+                ("2026-10-10".to_string(), None),
+                ("2026-10-20".to_string(), None),
+            ]
+        );
+    }
+}
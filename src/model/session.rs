@@ -0,0 +1,290 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::event::EventTime;
+use chrono::NaiveDate;
+use enum_iterator::{Sequence, all};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// A part of an event with its own time, such as a workshop before a social dance, or a concert
+/// on one day of a festival.
+#[derive(Clone, Debug, Deserialize, Eq, JsonSchema, PartialEq, Serialize)]
+pub struct Session {
+    /// The name of the session, if it has one more specific than its kind, e.g. "Beginners'
+    /// workshop".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub kind: SessionKind,
+    /// When the session takes place. This must be within the time of the event.
+    #[serde(flatten)]
+    pub time: EventTime,
+    /// The names of the bands playing in the session.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bands: Vec<String>,
+    /// The names of the callers calling in the session, if applicable.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub callers: Vec<String>,
+    /// The names of the teachers leading the session, if applicable.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub teachers: Vec<String>,
+}
+
+impl Session {
+    /// Checks that the session is valid and within the given time of its event. Returns an empty
+    /// list if it is, or a list of problems if not.
+    pub fn validate(&self, event_time: &EventTime) -> Vec<&'static str> {
+        let mut problems = vec![];
+        if self.time.start_time_sort_key() > self.time.end_time_sort_key() {
+            problems.push("Session start must be before or equal to end.");
+        }
+        if self.time.start_date() < event_time.start_date()
+            || self.time.end_date() > event_time.end_date()
+        {
+            problems.push("Sessions must be within the dates of the event.");
+        }
+        problems
+    }
+
+    /// Formats the session start and end, assuming that the month is already known.
+    pub fn short_time(&self) -> String {
+        match self.time {
+            EventTime::DateOnly {
+                start_date,
+                end_date,
+            } => {
+                if start_date == end_date {
+                    start_date.format("%a %-d").to_string()
+                } else {
+                    format!(
+                        "{}–{}",
+                        start_date.format("%a %-d"),
+                        end_date.format("%a %-d")
+                    )
+                }
+            }
+            EventTime::DateTime { start, end } => {
+                if start.date_naive() == end.date_naive() {
+                    format!(
+                        "{}–{}",
+                        start.format("%a %-d %-l:%M %P"),
+                        end.format("%-l:%M %P")
+                    )
+                } else {
+                    format!(
+                        "{}–{}",
+                        start.format("%a %-d %-l:%M %P"),
+                        end.format("%a %-d %-l:%M %P")
+                    )
+                }
+            }
+        }
+    }
+
+    /// Formats the session start and end time, assuming that the start date is already known.
+    pub fn time_no_date(&self) -> String {
+        match self.time {
+            EventTime::DateOnly {
+                start_date,
+                end_date,
+            } => {
+                if start_date == end_date {
+                    "all day".to_string()
+                } else {
+                    format!("until {}", end_date.format("%a %-d"))
+                }
+            }
+            EventTime::DateTime { start, end } => {
+                if start.date_naive() == end.date_naive() {
+                    format!("{}–{}", start.format("%-l:%M %P"), end.format("%-l:%M %P"))
+                } else {
+                    format!(
+                        "{}–{}",
+                        start.format("%-l:%M %P"),
+                        end.format("%a %-d %-l:%M %P")
+                    )
+                }
+            }
+        }
+    }
+
+    /// Returns a one-line plain text summary of the session, including its time, kind, name and
+    /// performers.
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{} {}", self.short_time(), self.kind);
+        if let Some(name) = &self.name {
+            summary += &format!(": {name}");
+        }
+        if !self.bands.is_empty() {
+            summary += &format!(", bands: {}", self.bands.join(", "));
+        }
+        if !self.callers.is_empty() {
+            summary += &format!(", callers: {}", self.callers.join(", "));
+        }
+        if !self.teachers.is_empty() {
+            summary += &format!(", teachers: {}", self.teachers.join(", "));
+        }
+        summary
+    }
+}
+
+/// The sessions and performers of one day of an event.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct LineupDay {
+    pub date: NaiveDate,
+    /// The sessions which start on the day, in order of start time.
+    pub sessions: Vec<Session>,
+    /// The bands playing in any session on the day.
+    pub bands: Vec<String>,
+    /// The callers calling in any session on the day.
+    pub callers: Vec<String>,
+    /// The teachers leading any session on the day.
+    pub teachers: Vec<String>,
+}
+
+impl LineupDay {
+    /// Formats the date of the day, e.g. "Saturday 4 July".
+    pub fn name(&self) -> String {
+        self.date.format("%A %-d %B").to_string()
+    }
+}
+
+/// Groups the given sessions by the day they start on, in order of date, listing the performers
+/// for each day.
+pub fn lineup(sessions: &[Session]) -> Vec<LineupDay> {
+    let mut sessions = sessions.to_vec();
+    sessions.sort_by_key(|session| session.time.start_time_sort_key());
+
+    let mut days: Vec<LineupDay> = vec![];
+    for session in sessions {
+        let date = session.time.start_date();
+        let day = match days.last_mut() {
+            Some(day) if day.date == date => day,
+            _ => {
+                days.push(LineupDay {
+                    date,
+                    sessions: vec![],
+                    bands: vec![],
+                    callers: vec![],
+                    teachers: vec![],
+                });
+                days.last_mut().unwrap()
+            }
+        };
+        add_missing(&mut day.bands, &session.bands);
+        add_missing(&mut day.callers, &session.callers);
+        add_missing(&mut day.teachers, &session.teachers);
+        day.sessions.push(session);
+    }
+    days
+}
+
+/// Adds any of the new names which aren't already in the list to the end of it.
+pub fn add_missing(names: &mut Vec<String>, new_names: &[String]) {
+    for name in new_names {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+}
+
+/// What happens in a session.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    JsonSchema,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Sequence,
+    Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionKind {
+    /// A workshop, class or lesson.
+    Workshop,
+    /// Social dancing.
+    Social,
+    /// Music to listen to rather than dance to.
+    Concert,
+}
+
+impl SessionKind {
+    pub fn values() -> impl Iterator<Item = Self> {
+        all::<Self>()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Workshop => "workshop",
+            Self::Social => "social",
+            Self::Concert => "concert",
+        }
+    }
+}
+
+impl Display for SessionKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lineup_by_day() {
+        let sessions: Vec<Session> = serde_yaml::from_str(
+            r#"
+- kind: social
+  start: 2026-07-04T20:00:00+02:00
+  end: 2026-07-05T01:00:00+02:00
+  bands: [Naragonia, Duo Absynthe]
+- kind: workshop
+  name: Scottish
+  start: 2026-07-04T14:00:00+02:00
+  end: 2026-07-04T16:00:00+02:00
+  teachers: [Anne]
+- kind: concert
+  start: 2026-07-05T11:00:00+02:00
+  end: 2026-07-05T12:00:00+02:00
+  bands: [Naragonia]
+"#,
+        )
+        .unwrap();
+
+        let days = lineup(&sessions);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].name(), "Saturday 4 July");
+        assert_eq!(
+            days[0]
+                .sessions
+                .iter()
+                .map(Session::time_no_date)
+                .collect::<Vec<_>>(),
+            vec!["2:00 pm–4:00 pm", "8:00 pm–Sun 5 1:00 am"]
+        );
+        assert_eq!(days[0].bands, vec!["Naragonia", "Duo Absynthe"]);
+        assert_eq!(days[0].teachers, vec!["Anne"]);
+        assert_eq!(days[1].date, NaiveDate::from_ymd_opt(2026, 7, 5).unwrap());
+        assert_eq!(days[1].bands, vec!["Naragonia"]);
+        assert!(days[1].teachers.is_empty());
+    }
+}
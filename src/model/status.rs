@@ -0,0 +1,107 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use enum_iterator::{Sequence, all};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// Whether an event is going ahead as planned.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Deserialize,
+    Eq,
+    Hash,
+    JsonSchema,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Sequence,
+    Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The event is going ahead as planned.
+    #[default]
+    Scheduled,
+    /// The event has been cancelled.
+    Cancelled,
+    /// The event won't happen at the given time, and a new time hasn't been decided yet.
+    Postponed,
+    /// The event has been moved to a different time, which may be given by `rescheduled_to`.
+    Rescheduled,
+    /// The event is going ahead, but there are no more tickets available.
+    SoldOut,
+    /// The event is planned but not yet confirmed.
+    Tentative,
+}
+
+impl Status {
+    pub fn values() -> impl Iterator<Item = Self> {
+        all::<Self>()
+    }
+
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Scheduled => "scheduled",
+            Self::Cancelled => "cancelled",
+            Self::Postponed => "postponed",
+            Self::Rescheduled => "rescheduled",
+            Self::SoldOut => "sold_out",
+            Self::Tentative => "tentative",
+        }
+    }
+
+    pub fn is_scheduled(&self) -> bool {
+        *self == Self::Scheduled
+    }
+
+    /// Returns whether the event won't be happening at the time given, either because it has been
+    /// cancelled or because it has been moved to another time.
+    pub fn is_called_off(self) -> bool {
+        matches!(self, Self::Cancelled | Self::Postponed | Self::Rescheduled)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Scheduled => "scheduled",
+            Self::Cancelled => "cancelled",
+            Self::Postponed => "postponed",
+            Self::Rescheduled => "rescheduled",
+            Self::SoldOut => "sold out",
+            Self::Tentative => "tentative",
+        })
+    }
+}
+
+/// Deserializes a status, or the legacy `cancelled` boolean.
+pub fn status_or_cancelled<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Status, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StatusOrCancelled {
+        Status(Status),
+        Cancelled(bool),
+    }
+
+    Ok(match StatusOrCancelled::deserialize(deserializer)? {
+        StatusOrCancelled::Status(status) => status,
+        StatusOrCancelled::Cancelled(true) => Status::Cancelled,
+        StatusOrCancelled::Cancelled(false) => Status::Scheduled,
+    })
+}
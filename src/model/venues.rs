@@ -0,0 +1,238 @@
+// Copyright 2026 the dancelist authors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{event::Event, registries::RegistryFile};
+use eyre::{Report, bail};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A venue which events can refer to by ID, so that its details don't need to be repeated in
+/// every event.
+#[derive(Clone, Debug, Deserialize, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Venue {
+    /// The unique ID by which events refer to the venue.
+    pub id: String,
+    /// The name of the venue.
+    pub name: String,
+    /// The full street address of the venue, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub city: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    pub country: String,
+    /// The latitude of the venue in degrees north, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    /// The longitude of the venue in degrees east, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+}
+
+impl Venue {
+    /// Returns a list of problems with the venue, or an empty list if there are none.
+    pub fn validate(&self) -> Vec<&'static str> {
+        let mut problems = vec![];
+
+        if self.id.is_empty() {
+            problems.push("Must have an ID.");
+        }
+        if self.name.is_empty() {
+            problems.push("Must have a name.");
+        }
+        if self.country.is_empty() {
+            problems.push("Must specify a country.");
+        }
+        if self.city.is_empty() {
+            problems.push("Must specify a city.");
+        }
+
+        match (self.latitude, self.longitude) {
+            (Some(latitude), Some(longitude)) => {
+                if !(-90.0..=90.0).contains(&latitude) {
+                    problems.push("Latitude must be between -90 and 90 degrees.");
+                }
+                if !(-180.0..=180.0).contains(&longitude) {
+                    problems.push("Longitude must be between -180 and 180 degrees.");
+                }
+            }
+            (None, None) => {}
+            _ => problems.push("Must specify both latitude and longitude, or neither."),
+        }
+
+        problems
+    }
+
+    /// Fills in any details of the venue which the event doesn't specify itself.
+    pub fn apply_to(&self, event: &mut Event) {
+        if event.venue.is_none() {
+            event.venue = Some(self.name.clone());
+        }
+        if event.address.is_none() {
+            event.address.clone_from(&self.address);
+        }
+        if event.city.is_empty() {
+            event.city.clone_from(&self.city);
+        }
+        if event.state.is_none() {
+            event.state.clone_from(&self.state);
+        }
+        if event.country.is_empty() {
+            event.country.clone_from(&self.country);
+        }
+        if event.coordinates().is_none() {
+            event.latitude = self.latitude;
+            event.longitude = self.longitude;
+        }
+    }
+
+    /// Clears any details of the event which are the same as the venue's, so that they aren't
+    /// repeated when the event is written out.
+    pub fn remove_from(&self, event: &mut Event) {
+        if event.venue.as_ref() == Some(&self.name) {
+            event.venue = None;
+        }
+        if event.address == self.address {
+            event.address = None;
+        }
+        if event.city == self.city {
+            event.city.clear();
+        }
+        if event.state == self.state {
+            event.state = None;
+        }
+        if event.country == self.country {
+            event.country.clear();
+        }
+        if event.latitude == self.latitude && event.longitude == self.longitude {
+            event.latitude = None;
+            event.longitude = None;
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, JsonSchema, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Venues {
+    pub venues: Vec<Venue>,
+}
+
+impl Venues {
+    /// Loads venues from the given YAML string.
+    ///
+    /// Doesn't validate the venues.
+    pub fn load_str(s: &str) -> Result<Self, Report> {
+        let venues = serde_yaml::from_str::<Venues>(s)?;
+        Ok(venues)
+    }
+
+    /// Returns the venue with the given ID, if there is one.
+    pub fn get(&self, id: &str) -> Option<&Venue> {
+        self.venues.iter().find(|venue| venue.id == id)
+    }
+}
+
+impl RegistryFile for Venues {
+    const FILENAME: &'static str = "venues.yaml";
+
+    fn extend(&mut self, other: Self) {
+        self.venues.extend(other.venues);
+    }
+
+    fn validate(&self) -> Result<(), Report> {
+        let mut ids = HashSet::new();
+        for venue in &self.venues {
+            let problems = venue.validate();
+            if !problems.is_empty() {
+                bail!("Problems with venue '{}': {:?}", venue.id, problems);
+            }
+            if !ids.insert(&venue.id) {
+                bail!("Duplicate venue ID '{}'", venue.id);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        dancestyle::DanceStyle,
+        event::{self, EventTime},
+    };
+    use chrono::NaiveDate;
+
+    fn test_event() -> Event {
+        Event {
+            time: EventTime::DateOnly {
+                start_date: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 1, 2).unwrap(),
+            },
+            country: String::new(),
+            state: Some("England".to_string()),
+            city: String::new(),
+            venue_id: Some("cecil-sharp-house".to_string()),
+            styles: vec![DanceStyle::EnglishCeilidh],
+            ..event::test_event()
+        }
+    }
+
+    fn test_venues() -> Venues {
+        Venues::load_str(
+            r#"
+venues:
+  - id: cecil-sharp-house
+    name: Cecil Sharp House
+    address: 2 Regent's Park Road, London NW1 7AY
+    city: London
+    country: UK
+    latitude: 51.5388
+    longitude: -0.1497
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn apply_and_remove() {
+        let venues = test_venues();
+        let venue = venues.get("cecil-sharp-house").unwrap();
+        let mut event = test_event();
+
+        venue.apply_to(&mut event);
+        assert_eq!(event.venue.as_deref(), Some("Cecil Sharp House"));
+        assert_eq!(
+            event.address.as_deref(),
+            Some("2 Regent's Park Road, London NW1 7AY")
+        );
+        assert_eq!(event.city, "London");
+        assert_eq!(event.country, "UK");
+        // Details given by the event itself take precedence.
+        assert_eq!(event.state.as_deref(), Some("England"));
+        assert_eq!(event.coordinates(), Some((51.5388, -0.1497)));
+
+        venue.remove_from(&mut event);
+        assert_eq!(event, test_event());
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        let mut venues = test_venues();
+        venues.venues.push(venues.venues[0].clone());
+        assert!(venues.validate().is_err());
+    }
+}
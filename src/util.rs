@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use askama::{Values, filter_fn};
-use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, TimeZone};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone};
 use chrono_tz::Tz;
+use deunicode::deunicode;
+use serde::{Deserialize, Deserializer, de::IntoDeserializer};
 
 pub const DEFAULT_TIMEZONES: [((&str, Option<&str>), Tz); 74] = [
     (("Australia", Some("QLD")), Tz::Australia__Brisbane),
@@ -117,7 +119,59 @@ pub fn local_datetime_to_fixed_offset(
     ))
 }
 
+/// Converts the given string to lowercase ASCII, for case- and accent-insensitive searching.
+pub fn fold_for_search(s: &str) -> String {
+    deunicode(s).to_lowercase()
+}
+
+/// Converts the given string to a lowercase ASCII slug for use in URLs, with runs of any other
+/// characters replaced by a single hyphen.
+pub fn slugify(s: &str) -> String {
+    deunicode(s)
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Deserializes an optional date, treating an empty string as `None`.
+///
+/// This is useful for HTML forms, which submit empty date fields as empty strings.
+pub fn date_or_none<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NaiveDate>, D::Error> {
+    if let Some(str) = Option::<String>::deserialize(deserializer)? {
+        if str.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(NaiveDate::deserialize(str.into_deserializer())?))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
 #[filter_fn]
 pub fn checked_if_true(value: bool, _values: &dyn Values) -> askama::Result<&'static str> {
     Ok(if value { "checked=\"checked\"" } else { "" })
 }
+
+#[filter_fn]
+pub fn slug(value: &str, _values: &dyn Values) -> askama::Result<String> {
+    Ok(slugify(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs() {
+        assert_eq!(slugify("Folkdance Dunedin"), "folkdance-dunedin");
+        assert_eq!(
+            slugify("  Bal'Folk Zürich -- Nord "),
+            "bal-folk-zurich-nord"
+        );
+    }
+}